@@ -27,9 +27,17 @@ fn impl_name_by_field(ast: syn::MacroInput) -> quote::Tokens {
                 let f_name = &f.ident;
                 let s = f_name.to_string();
 
-                quote!(
-                	#name::#f_name => { return #s.to_string(); }
-                )
+                match f.data {
+                    syn::VariantData::Struct(_) => quote!(
+                        #name::#f_name { .. } => { return #s.to_string(); }
+                    ),
+                    syn::VariantData::Tuple(_) => quote!(
+                        #name::#f_name(..) => { return #s.to_string(); }
+                    ),
+                    syn::VariantData::Unit => quote!(
+                        #name::#f_name => { return #s.to_string(); }
+                    ),
+                }
             });
 
 	        let q = quote! {
@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::env;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Lang {
+    En,
+    Ru,
+}
+
+impl Lang {
+    pub fn from_name(name: &str) -> Option<Lang> {
+        match &*name.to_lowercase() {
+            "en" => Some(Lang::En),
+            "ru" => Some(Lang::Ru),
+            _ => None,
+        }
+    }
+
+    pub fn from_env() -> Lang {
+        match env::var("LANG") {
+            Ok(ref val) if val.to_lowercase().starts_with("en") => Lang::En,
+            _ => Lang::Ru,
+        }
+    }
+}
+
+lazy_static! {
+    static ref RU: HashMap<&'static str, &'static str> = {
+        let mut m = HashMap::new();
+        m.insert("E0001", "Неверный формат целого числа");
+        m.insert("E0002", "Неверный формат шестнадцатеричного числа");
+        m.insert("E0003", "Неверный формат двоичного числа");
+        m.insert("E0004", "Неверный формат восьмиричного числа");
+        m.insert("E0005", "Неверный формат вешественного числа");
+        m.insert("E0006", "Неверный формат экспоненты");
+        m.insert("E0007", "Неизвестный код символа");
+        m.insert("E0008", "Недопустимый символ");
+
+        m.insert("E0101", "Пропущен операнд");
+        m.insert("E0102", "Ожидалось {0}");
+        m.insert("E0103", "Слишком много ошибок ({0}), разбор прерван");
+
+        m.insert("E0201", "Идентификатор {0} уже определен");
+        m.insert("E0202", "Идентификатор {0} неизвестен");
+        m.insert("E0203", "Нельзя вызвать {0} как функцию");
+        m.insert("E0204", "Не найдена перегрузка {1} для {0}");
+        m.insert("E0205", "Неоднозначный вызов {0}, подходят: {1}");
+        m.insert("E0206", "Нельзя преобразовать {0} в {1}");
+        m.insert("E0207", "Невозможно применить {0} к {1}");
+        m.insert("E0208", "Невозможно {0} {1} c {2}");
+        m.insert("E0209", "Не найдено определение для {0}{1}");
+        m.insert("E0210", "Невозможно привести {0} к {1}");
+        m.insert("E0211", "Нельзя обратиться по индексу к {0}");
+        m.insert("E0212", "Нельзя обратиться к полям {0}");
+        m.insert("E0213", "Поле {0} у {1} не существует");
+        m.insert("E0214", "Неверный тип индекса {0}, ожидался {1}");
+        m.insert("E0215", "Нельзя создать range из {0} и {1}");
+        m.insert("E0216", "Ожидалось аргументов: {0}, передано: {1}");
+        m.insert("E0217", "{0} вызван не в цикле");
+        m.insert("E0218", "Ожидался тип");
+        m.insert("E0219", "Ожидался перечислимый тип");
+        m.insert("E0220", "Ожидалось логическое выражение");
+        m.insert("E0221", "Ожидалось * / + - or and или операторы сравнения");
+        m.insert("E0222", "Ожидалось + - not");
+        m.insert("E0223", "Ожидалось += -= := *= /=");
+        m.insert("E0224", "Слишком много элементов массива");
+        m.insert("E0225", "Неверно заданы границы range");
+        m.insert("E0226", "Неверный тип выражения");
+        m.insert("E0227", "Недопустимое выражение");
+        m.insert("E0228", "Ошибка при перегрузке функции");
+        m.insert("E0229", "Индекс {0} выходит за границы {1}");
+        m.insert("E0230", "Ожидалось выражение, вычислимое при компиляции");
+        m.insert("E0231", "Ожидалась переменная");
+        m.insert("E0232", "Значение типа {0} передается только через var-параметр");
+
+        m.insert("op.unar_plus",  "унарный плюс");
+        m.insert("op.unar_minus", "унарный минус");
+        m.insert("op.not",        "унарное not");
+        m.insert("op.succ",       "succ");
+        m.insert("op.pred",       "pred");
+        m.insert("op.ord",        "ord");
+        m.insert("op.plus",       "сложить");
+        m.insert("op.minus",      "отнять");
+        m.insert("op.mul",        "умножить");
+        m.insert("op.share",      "поделить");
+        m.insert("op.div",        "применить Div к");
+        m.insert("op.mod",        "применить Mod к");
+        m.insert("op.and",        "применить And");
+        m.insert("op.or",         "применить Or");
+        m.insert("op.compare",    "сравнить");
+
+        m.insert("token.identifier", "идентификатор");
+        m.insert("token.integer",    "целое число");
+        m.insert("token.real",       "вещественное число");
+        m.insert("token.character",  "символ");
+
+        m.insert("label.error",     "ошибка");
+        m.insert("label.note",      "примечание");
+        m.insert("label.fatal",     "Ошибка");
+        m.insert("note.previous_declaration", "предыдущее объявление здесь");
+
+        m.insert("cli.usage",                  "Использование: PasC [опции] файл...");
+        m.insert("cli.expects_value",          "{0} ожидает значение");
+        m.insert("cli.unknown_emit",           "неизвестный режим --emit {0}");
+        m.insert("cli.repeated_output",        "-o указан несколько раз");
+        m.insert("cli.expects_count",          "-e ожидает число");
+        m.insert("cli.expects_lang",           "--lang ожидает en или ru");
+        m.insert("cli.expects_error_format",   "--error-format ожидает human или json");
+        m.insert("cli.unknown_target",         "неизвестная целевая платформа {0}");
+        m.insert("cli.expects_checks",         "--checks ожидает список из bounds, range, overflow");
+        m.insert("cli.unknown_option",         "неизвестная опция {0}");
+        m.insert("cli.exclusive_stages",       "режимы -l, -p, -g и --emit взаимоисключающие");
+        m.insert("cli.no_input",               "не указан входной файл");
+        m.insert("cli.output_with_many_inputs", "-o можно использовать только с одним входным файлом");
+        m.insert("cli.output_with_in_file",    "-o и -f взаимоисключающие");
+        m.insert("cli.stdin_twice",            "stdin можно указать только один раз");
+
+        m.insert("help.help",          "справка");
+        m.insert("help.tokens",        "то же, что --emit tokens");
+        m.insert("help.ast",           "то же, что --emit ast");
+        m.insert("help.asm",           "то же, что --emit asm");
+        m.insert("help.emit",          "этап, после которого остановиться (по умолчанию asm)");
+        m.insert("help.output",        "записать результат в path (только с одним входным файлом)");
+        m.insert("help.in_file",       "записать результат в <файл>.res");
+        m.insert("help.max_errors",    "остановиться после count ошибок (по умолчанию 20)");
+        m.insert("help.lang",          "язык диагностик (по умолчанию из LANG)");
+        m.insert("help.error_format",  "формат диагностик");
+        m.insert("help.lexer_table",   "взять таблицу переходов из file");
+        m.insert("help.assembler",     "ассемблер для --emit obj|exe (по умолчанию nasm)");
+        m.insert("help.linker",        "компоновщик для --emit exe (по умолчанию gcc)");
+        m.insert("help.target",        "целевая платформа (по умолчанию elf32-linux)");
+        m.insert("help.short_circuit", "вычислять and/or над логическими значениями сокращенно");
+        m.insert("help.checks",        "проверки во время выполнения (по умолчанию нет)");
+        m.insert("help.stdin",         "читать исходный текст из stdin");
+        m.insert("help.exit_codes",    "коды возврата: 2 ошибка в опциях, 3 ввод-вывод, 4 лексическая, 5 синтаксическая, 6 семантическая ошибка, 7 ошибка ассемблера или компоновщика");
+
+        m.insert("io.cannot_read",       "не удалось прочитать файл {0}");
+        m.insert("io.cannot_write",      "не удалось записать файл {0}");
+        m.insert("io.cannot_read_table", "не удалось прочитать таблицу переходов {0}");
+
+        m.insert("tool.failed",    "{0} завершился с ошибкой ({1})");
+        m.insert("tool.not_found", "не найдена программа {0}, установите ее или укажите другую через опции");
+        m.insert("tool.cannot_run", "не удалось запустить {0}: {1}");
+
+        m.insert("runtime.check_failed", "Ошибка выполнения: проверка {0} не пройдена в строке {1}");
+        m
+    };
+
+    static ref EN: HashMap<&'static str, &'static str> = {
+        let mut m = HashMap::new();
+        m.insert("E0001", "Invalid integer literal");
+        m.insert("E0002", "Invalid hexadecimal literal");
+        m.insert("E0003", "Invalid binary literal");
+        m.insert("E0004", "Invalid octal literal");
+        m.insert("E0005", "Invalid real literal");
+        m.insert("E0006", "Invalid exponent");
+        m.insert("E0007", "Unknown character code");
+        m.insert("E0008", "Unexpected character");
+
+        m.insert("E0101", "Missing operand");
+        m.insert("E0102", "Expected {0}");
+        m.insert("E0103", "Too many errors ({0}), parsing aborted");
+
+        m.insert("E0201", "Identifier {0} is already defined");
+        m.insert("E0202", "Unknown identifier {0}");
+        m.insert("E0203", "Cannot call {0} as a function");
+        m.insert("E0204", "No overload {1} found for {0}");
+        m.insert("E0205", "Ambiguous call to {0}, candidates: {1}");
+        m.insert("E0206", "Cannot convert {0} to {1}");
+        m.insert("E0207", "Cannot apply {0} to {1}");
+        m.insert("E0208", "Cannot {0} {1} and {2}");
+        m.insert("E0209", "No definition found for {0}{1}");
+        m.insert("E0210", "Cannot assign {0} to {1}");
+        m.insert("E0211", "Cannot index {0}");
+        m.insert("E0212", "{0} has no fields");
+        m.insert("E0213", "Field {0} does not exist in {1}");
+        m.insert("E0214", "Invalid index type {0}, expected {1}");
+        m.insert("E0215", "Cannot create a range from {0} and {1}");
+        m.insert("E0216", "Expected {0} arguments, got {1}");
+        m.insert("E0217", "{0} used outside of a loop");
+        m.insert("E0218", "Expected a type");
+        m.insert("E0219", "Expected an ordinal type");
+        m.insert("E0220", "Expected a boolean expression");
+        m.insert("E0221", "Expected * / + - or and or a comparison operator");
+        m.insert("E0222", "Expected + - not");
+        m.insert("E0223", "Expected += -= := *= /=");
+        m.insert("E0224", "Too many array elements");
+        m.insert("E0225", "Invalid range bounds");
+        m.insert("E0226", "Invalid expression type");
+        m.insert("E0227", "Invalid expression");
+        m.insert("E0228", "Invalid function overload");
+        m.insert("E0229", "Index {0} is out of bounds {1}");
+        m.insert("E0230", "Expected an expression computable at compile time");
+        m.insert("E0231", "Expected a variable");
+        m.insert("E0232", "A value of type {0} can only be passed as a var parameter");
+
+        m.insert("op.unar_plus",  "unary plus");
+        m.insert("op.unar_minus", "unary minus");
+        m.insert("op.not",        "unary not");
+        m.insert("op.succ",       "succ");
+        m.insert("op.pred",       "pred");
+        m.insert("op.ord",        "ord");
+        m.insert("op.plus",       "add");
+        m.insert("op.minus",      "subtract");
+        m.insert("op.mul",        "multiply");
+        m.insert("op.share",      "divide");
+        m.insert("op.div",        "apply Div to");
+        m.insert("op.mod",        "apply Mod to");
+        m.insert("op.and",        "apply And to");
+        m.insert("op.or",         "apply Or to");
+        m.insert("op.compare",    "compare");
+
+        m.insert("token.identifier", "identifier");
+        m.insert("token.integer",    "integer");
+        m.insert("token.real",       "real number");
+        m.insert("token.character",  "character");
+
+        m.insert("label.error",     "error");
+        m.insert("label.note",      "note");
+        m.insert("label.fatal",     "Error");
+        m.insert("note.previous_declaration", "previous declaration here");
+
+        m.insert("cli.usage",                  "Usage: PasC [options] file...");
+        m.insert("cli.expects_value",          "{0} expects a value");
+        m.insert("cli.unknown_emit",           "unknown --emit stage {0}");
+        m.insert("cli.repeated_output",        "-o is given more than once");
+        m.insert("cli.expects_count",          "-e expects a number");
+        m.insert("cli.expects_lang",           "--lang expects en or ru");
+        m.insert("cli.expects_error_format",   "--error-format expects human or json");
+        m.insert("cli.unknown_target",         "unknown target platform {0}");
+        m.insert("cli.expects_checks",         "--checks expects a list of bounds, range, overflow");
+        m.insert("cli.unknown_option",         "unknown option {0}");
+        m.insert("cli.exclusive_stages",       "-l, -p, -g and --emit are mutually exclusive");
+        m.insert("cli.no_input",               "no input file");
+        m.insert("cli.output_with_many_inputs", "-o can only be used with one input file");
+        m.insert("cli.output_with_in_file",    "-o and -f are mutually exclusive");
+        m.insert("cli.stdin_twice",            "stdin can only be given once");
+
+        m.insert("help.help",          "help");
+        m.insert("help.tokens",        "the same as --emit tokens");
+        m.insert("help.ast",           "the same as --emit ast");
+        m.insert("help.asm",           "the same as --emit asm");
+        m.insert("help.emit",          "stage to stop after (asm by default)");
+        m.insert("help.output",        "write output to path (only with one input file)");
+        m.insert("help.in_file",       "write output to <file>.res");
+        m.insert("help.max_errors",    "stop after count errors (20 by default)");
+        m.insert("help.lang",          "language of diagnostics (LANG by default)");
+        m.insert("help.error_format",  "format of diagnostics");
+        m.insert("help.lexer_table",   "use transition table from file");
+        m.insert("help.assembler",     "assembler for --emit obj|exe (nasm by default)");
+        m.insert("help.linker",        "linker for --emit exe (gcc by default)");
+        m.insert("help.target",        "target platform (elf32-linux by default)");
+        m.insert("help.short_circuit", "evaluate and/or on booleans lazily");
+        m.insert("help.checks",        "runtime checks to insert (none by default)");
+        m.insert("help.stdin",         "read source from stdin");
+        m.insert("help.exit_codes",    "exit codes: 2 usage, 3 i/o, 4 lexical, 5 syntax, 6 semantic error, 7 assembler/linker error");
+
+        m.insert("io.cannot_read",       "cannot read file {0}");
+        m.insert("io.cannot_write",      "cannot write file {0}");
+        m.insert("io.cannot_read_table", "cannot read transition table {0}");
+
+        m.insert("tool.failed",    "{0} failed ({1})");
+        m.insert("tool.not_found", "program {0} not found, install it or choose another one with the options");
+        m.insert("tool.cannot_run", "cannot run {0}: {1}");
+
+        m.insert("runtime.check_failed", "Runtime error: {0} check failed at line {1}");
+        m
+    };
+}
+
+pub fn message(key: &str, lang: Lang) -> String {
+    let catalog = match lang {
+        Lang::En => &*EN,
+        Lang::Ru => &*RU,
+    };
+    match catalog.get(key) {
+        Some(res) => res.to_string(),
+        None => key.to_string(),
+    }
+}
+
+pub fn format_message(key: &str, args: &[String], lang: Lang) -> String {
+    let mut ans = message(key, lang);
+    for (i, arg) in args.iter().enumerate() {
+        ans = ans.replace(&format!("{{{}}}", i), arg);
+    }
+
+    ans
+}
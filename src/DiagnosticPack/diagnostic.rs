@@ -0,0 +1,273 @@
+use std::cmp;
+use support::*;
+use ParserPack::Types::support::*;
+use TokenizerPack::support::Point;
+use DiagnosticPack::catalog::*;
+
+pub struct Note {
+    pub msg: String,
+    pub coords: Point,
+}
+
+pub enum ErrorFormat {
+    Human,
+    Json,
+}
+
+impl ErrorFormat {
+    pub fn from_name(name: &str) -> Option<ErrorFormat> {
+        match name {
+            "human" => Some(ErrorFormat::Human),
+            "json" => Some(ErrorFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+pub struct Diagnostic {
+    pub kind: String,
+    pub name: String,
+    pub code: String,
+    pub msg: String,
+    pub coords: Option<Point>,
+    pub notes: Vec<Note>,
+    pub lang: Lang,
+}
+
+impl Diagnostic {
+    pub fn new(err: CompilerErrors, lang: Lang) -> Diagnostic {
+        let mut notes = Vec::new();
+        let (kind, name) = match err {
+            CompilerErrors::TokenizerError { ref err } => ("tokenizer", err.fields_name()),
+            CompilerErrors::ParserError { ref err } => ("parser", err.fields_name()),
+            CompilerErrors::SemanticError { ref err, .. } => ("semantic", err.fields_name()),
+        };
+
+        let (code, args, coords) = match err {
+            CompilerErrors::TokenizerError { err } => {
+                let (code, x, y) = match err {
+                    TokenizerErrors::ErrorInInteger { x, y } => ("E0001", x, y),
+                    TokenizerErrors::ErrorInHex { x, y } => ("E0002", x, y),
+                    TokenizerErrors::ErrorInBin { x, y } => ("E0003", x, y),
+                    TokenizerErrors::ErrorInOctal { x, y } => ("E0004", x, y),
+                    TokenizerErrors::ErrorInDouble { x, y } => ("E0005", x, y),
+                    TokenizerErrors::ErrorInExp { x, y } => ("E0006", x, y),
+                    TokenizerErrors::UnknownCharCode { x, y } => ("E0007", x, y),
+                    TokenizerErrors::SimpleError { x, y } => ("E0008", x, y),
+                };
+                (code, vec![], Some(Point { x, y, len: 1 }))
+            }
+            CompilerErrors::ParserError { err } => match err {
+                ParserErrors::MissingOperand { x, y, len } => {
+                    ("E0101", vec![], Some(Point { x, y, len }))
+                }
+                ParserErrors::ExpectedToken { x, y, len, token } => {
+                    let key = "token.".to_string() + &token;
+                    let token = if message(&key, lang) == key {
+                        token
+                    } else {
+                        message(&key, lang)
+                    };
+                    ("E0102", vec![token], Some(Point { x, y, len }))
+                }
+                ParserErrors::TooManyErrors { count } => ("E0103", vec![count.to_string()], None),
+            },
+            CompilerErrors::SemanticError { err, x, y, len } => {
+                let (code, args) = match err {
+                    SemanticErrors::DuplicateIdentifier { name, prev } => {
+                        if prev.y > 0 {
+                            notes.push(Note {
+                                msg: message("note.previous_declaration", lang),
+                                coords: prev,
+                            });
+                        }
+                        ("E0201", vec![name])
+                    }
+                    SemanticErrors::UnknownIdentifier { name } => ("E0202", vec![name]),
+                    SemanticErrors::NotAFunction { name } => ("E0203", vec![name]),
+                    SemanticErrors::UnknownOverride { name, sign } => ("E0204", vec![name, sign]),
+                    SemanticErrors::AmbiguousOverride { name, signs } => {
+                        ("E0205", vec![name, signs.join("; ")])
+                    }
+                    SemanticErrors::CastError { this, other } => ("E0206", vec![this, other]),
+                    SemanticErrors::ErrorInUnarOperation { name, op } => {
+                        let op = match op {
+                            UnarOperation::Plus => "op.unar_plus",
+                            UnarOperation::Minus => "op.unar_minus",
+                            UnarOperation::Not => "op.not",
+                            UnarOperation::Succ => "op.succ",
+                            UnarOperation::Pred => "op.pred",
+                            UnarOperation::Ord => "op.ord",
+                        };
+                        ("E0207", vec![message(op, lang), name])
+                    }
+                    SemanticErrors::ErrorInBinOperation { left, right, op } => {
+                        let op = match op {
+                            BinOperation::Plus => "op.plus",
+                            BinOperation::Minus => "op.minus",
+                            BinOperation::Mul => "op.mul",
+                            BinOperation::Share => "op.share",
+                            BinOperation::Div => "op.div",
+                            BinOperation::Mod => "op.mod",
+                            BinOperation::And => "op.and",
+                            BinOperation::Or => "op.or",
+                            _ => "op.compare",
+                        };
+                        ("E0208", vec![message(op, lang), left, right])
+                    }
+                    SemanticErrors::ErrorInForwardDecl { name, sign } => ("E0209", vec![name, sign]),
+                    SemanticErrors::InvalidConversion { from, to } => ("E0210", vec![from, to]),
+                    SemanticErrors::NotIndexable { name } => ("E0211", vec![name]),
+                    SemanticErrors::NoFields { name } => ("E0212", vec![name]),
+                    SemanticErrors::UnknownField { field, name } => ("E0213", vec![field, name]),
+                    SemanticErrors::InvalidIndexType { index, expected } => {
+                        ("E0214", vec![index, expected])
+                    }
+                    SemanticErrors::InvalidRangeBounds { left, right } => {
+                        ("E0215", vec![left, right])
+                    }
+                    SemanticErrors::IndexOutOfRange { index, expected } => {
+                        ("E0229", vec![index, expected])
+                    }
+                    SemanticErrors::ArgCountMismatch { expected, passed } => {
+                        ("E0216", vec![expected.to_string(), passed.to_string()])
+                    }
+                    SemanticErrors::NotInLoop { kind } => ("E0217", vec![kind]),
+                    SemanticErrors::AggregateByValue { name } => ("E0232", vec![name]),
+                    SemanticErrors::ExpectedType => ("E0218", vec![]),
+                    SemanticErrors::ExpectedEnumerated => ("E0219", vec![]),
+                    SemanticErrors::ExpectedBoolean => ("E0220", vec![]),
+                    SemanticErrors::ExpectedBinOperator => ("E0221", vec![]),
+                    SemanticErrors::ExpectedUnarOperator => ("E0222", vec![]),
+                    SemanticErrors::ExpectedAssignOperator => ("E0223", vec![]),
+                    SemanticErrors::ArrayTooLarge => ("E0224", vec![]),
+                    SemanticErrors::InvalidRange => ("E0225", vec![]),
+                    SemanticErrors::InvalidExpressionType => ("E0226", vec![]),
+                    SemanticErrors::InvalidExpression => ("E0227", vec![]),
+                    SemanticErrors::InvalidOverride => ("E0228", vec![]),
+                    SemanticErrors::ExpectedConstant => ("E0230", vec![]),
+                    SemanticErrors::ExpectedVariable => ("E0231", vec![]),
+                };
+                (code, args, Some(Point { x, y, len }))
+            }
+        };
+
+        Diagnostic {
+            kind: kind.to_string(),
+            name,
+            code: code.to_string(),
+            msg: format_message(code, &args, lang),
+            coords,
+            notes,
+            lang,
+        }
+    }
+
+    pub fn render(&self, file_name: &str, lines: &[String]) -> String {
+        let mut width = 0;
+        for c in self.coords.iter().chain(self.notes.iter().map(|n| &n.coords)) {
+            width = cmp::max(width, c.y.to_string().len());
+        }
+
+        let mut ans = format!(
+            "{}[{}]: {}\n",
+            message("label.error", self.lang),
+            self.code,
+            self.msg
+        );
+        if let Some(ref c) = self.coords {
+            ans += &excerpt(c, file_name, lines, width);
+        }
+        for note in &self.notes {
+            ans += &format!("{}: {}\n", message("label.note", self.lang), note.msg);
+            ans += &excerpt(&note.coords, file_name, lines, width);
+        }
+
+        ans
+    }
+
+    pub fn to_json(&self, file_name: &str) -> String {
+        let related: Vec<String> = self.notes
+            .iter()
+            .map(|note| {
+                format!(
+                    "{{\"message\":{},{}}}",
+                    json_string(&note.msg),
+                    json_span(&Some(note.coords.clone()))
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"kind\":{},\"name\":{},\"code\":{},\"message\":{},\"file\":{},{},\"related\":[{}]}}",
+            json_string(&self.kind),
+            json_string(&self.name),
+            json_string(&self.code),
+            json_string(&self.msg),
+            json_string(file_name),
+            json_span(&self.coords),
+            related.join(",")
+        )
+    }
+}
+
+fn json_span(coords: &Option<Point>) -> String {
+    match *coords {
+        Some(ref c) => format!("\"line\":{},\"column\":{},\"length\":{}", c.y, c.x, c.len),
+        None => "\"line\":null,\"column\":null,\"length\":null".to_string(),
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut ans = "\"".to_string();
+    for ch in text.chars() {
+        match ch {
+            '"' => ans += "\\\"",
+            '\\' => ans += "\\\\",
+            '\n' => ans += "\\n",
+            '\r' => ans += "\\r",
+            '\t' => ans += "\\t",
+            ch if (ch as u32) < 0x20 => ans += &format!("\\u{:04x}", ch as u32),
+            ch => ans.push(ch),
+        }
+    }
+    ans.push('"');
+
+    ans
+}
+
+fn excerpt(coords: &Point, file_name: &str, lines: &[String], width: usize) -> String {
+    let pad = " ".repeat(width);
+    let mut ans = format!("{}--> {}:{}:{}\n", pad, file_name, coords.y, coords.x);
+
+    let line = match lines.get((coords.y - 1) as usize) {
+        Some(line) if coords.y > 0 => expand_tabs(line),
+        _ => return ans,
+    };
+
+    let shift = " ".repeat(cmp::max(coords.x - 1, 0) as usize);
+    let underline = "^".to_string() + &"~".repeat(cmp::max(coords.len - 1, 0) as usize);
+
+    ans += &format!("{} |\n", pad);
+    ans += &format!("{:>w$} | {}\n", coords.y, line, w = width);
+    ans += &format!("{} | {}{}\n", pad, shift, underline);
+
+    ans
+}
+
+fn expand_tabs(line: &str) -> String {
+    let mut ans = String::new();
+    let mut col = 0;
+    for ch in line.chars() {
+        if ch == '\t' {
+            let next = col + 4 - (col % 4);
+            ans += &" ".repeat(next - col);
+            col = next;
+        } else {
+            ans.push(ch);
+            col += 1;
+        }
+    }
+
+    ans
+}
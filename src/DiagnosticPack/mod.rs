@@ -0,0 +1,5 @@
+pub mod catalog;
+pub mod diagnostic;
+
+pub use self::catalog::*;
+pub use self::diagnostic::*;
@@ -0,0 +1,94 @@
+use TokenizerPack::*;
+use GeneratorPack::command::*;
+
+// Команды, зависящие от архитектуры. Генератор - стековая машина: целое значение
+// занимает на стеке одно слово (word_size байт), double - float_size байт,
+// вещественные операнды перед операцией подготавливаются
+// create_push_to_fld/create_push_int_to_fld.
+pub trait Backend {
+    fn word_size(&self) -> i32;
+    fn float_size(&self) -> i32;
+    fn frame_reg(&self) -> String;
+    fn link_reg(&self) -> String;
+    // Спецификатор printf для целого размером в слово
+    fn int_format(&self) -> String;
+
+    fn create_main_entry(&self, symbol: String) -> Command;
+    fn create_main_exit(&self) -> Command;
+
+    fn create_int_var(&self, name: String, value: i64) -> Command;
+    fn create_float_var(&self, name: String, value: f64) -> Command;
+
+    fn create_push_const(&self, value: i64) -> Command;
+    fn create_push_float_const(&self, value: f64) -> Command;
+    fn create_push_var(&self, addr: String) -> Command;
+    fn create_push_float_var(&self, addr: String) -> Command;
+    fn create_push_var_addr(&self, addr: String) -> Command;
+    fn create_push_int_result(&self) -> Command;
+
+    fn create_int_op(&self, op: TokenType) -> Command;
+    fn create_int_unar_op(&self, op: TokenType) -> Command;
+    fn create_int_add_const(&self, value: i64) -> Command;
+    fn create_index_offset(&self, left: i64, stride: i64) -> Command;
+    fn create_bool_not(&self) -> Command;
+    fn create_float_op(&self, op: TokenType) -> Command;
+    fn create_float_unar_op(&self, op: TokenType) -> Command;
+    fn create_assign(&self, addr: String) -> Command;
+    fn create_float_assign(&self, addr: String) -> Command;
+
+    fn create_enter(&self, locals_size: i32) -> Command;
+    fn create_leave(&self, shift: i32) -> Command;
+    fn create_static_link_walk(&self, count: usize) -> Command;
+    fn create_load_ref(&self, addr: String) -> Command;
+    fn create_load_int_result(&self, addr: String) -> Command;
+    fn create_load_float_result(&self, addr: String) -> Command;
+    fn create_push_float_result(&self) -> Command;
+
+    fn create_int_to_float(&self) -> Command;
+    fn create_clear_stack(&self, shift: i32) -> Command;
+    fn create_push_to_fld(&self) -> Command;
+    fn create_push_int_to_fld(&self) -> Command;
+
+    fn create_int_cmp(&self, op: TokenType) -> Command;
+    fn create_float_cmp(&self, op: TokenType) -> Command;
+    fn create_jump_if_false(&self, label: String) -> Command;
+    fn create_jump_if_true(&self, label: String) -> Command;
+    fn create_int_cmp_jump_if_false(&self, op: TokenType, label: String) -> Command;
+    fn create_float_cmp_jump_if_false(&self, op: TokenType, label: String) -> Command;
+    fn create_for_init(&self, addr: String, end_label: String) -> Command;
+    fn create_for_step(&self, addr: String, body_label: String, end_label: String) -> Command;
+
+    // Проверяет, что значение на вершине стека лежит в [left, right], не снимая его
+    fn create_range_guard(&self, left: i64, right: i64, ok_label: String, fail_label: String) -> Command;
+
+    // Таблица адресов строк FALSE и TRUE для writeln
+    fn create_bool_names(&self, label: String) -> Command;
+    fn create_bool_to_str(&self, label: String) -> Command;
+
+    // Аргументы уже лежат на стеке, первый - на вершине; doubles отмечает вещественные
+    fn create_call_printf(&self, symbol: String, format: String, doubles: &Vec<bool>) -> Command;
+    fn create_call_exit(&self, symbol: String, code: i32) -> Command;
+}
+
+pub fn int_setcc(op: TokenType) -> String {
+    match op {
+        TokenType::TGe => "setge",
+        TokenType::TGt => "setg",
+        TokenType::TEq => "sete",
+        TokenType::TLe => "setle",
+        TokenType::TLt => "setl",
+        _ => "setne",
+    }.to_string()
+}
+
+// fcomip и ucomisd выставляют флаги как беззнаковое сравнение
+pub fn float_setcc(op: TokenType) -> String {
+    match op {
+        TokenType::TGe => "setae",
+        TokenType::TGt => "seta",
+        TokenType::TEq => "sete",
+        TokenType::TLe => "setbe",
+        TokenType::TLt => "setb",
+        _ => "setne",
+    }.to_string()
+}
@@ -0,0 +1,31 @@
+// Проверки времени выполнения, включаемые --checks
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Checks {
+    pub bounds: bool,
+    pub range: bool,
+    pub overflow: bool,
+}
+
+impl Checks {
+    pub fn none() -> Checks {
+        Checks {
+            bounds: false,
+            range: false,
+            overflow: false,
+        }
+    }
+
+    // Список через запятую, например bounds,overflow
+    pub fn from_list(list: &str) -> Option<Checks> {
+        let mut res = Checks::none();
+        for name in list.split(',') {
+            match name.trim() {
+                "bounds" => res.bounds = true,
+                "range" => res.range = true,
+                "overflow" => res.overflow = true,
+                _ => return None,
+            }
+        }
+        Some(res)
+    }
+}
@@ -1,22 +1,9 @@
-use TokenizerPack::*;
-
 pub struct Command {
     text: String,
 }
 
 impl Command {
-    pub fn create_int_var(name: String) -> Command {
-        let text = "v_".to_string() + &name + " : dd 0";
-        Command { text }
-    }
-
-    pub fn create_float_var(name: String) -> Command {
-        let text = "v_".to_string() + &name + " : dq 0";
-        Command { text }
-    }
-
-    pub fn create_char_var(name: String) -> Command {
-        let text = "v_".to_string() + &name + " : db 0";
+    pub fn new(text: String) -> Command {
         Command { text }
     }
 
@@ -25,8 +12,8 @@ impl Command {
         Command { text }
     }
 
-    pub fn create_push_const(value: i32) -> Command {
-        let text = "push ".to_string() + &value.to_string();
+    pub fn create_string(name: String, text: String) -> Command {
+        let text = name + " : db \"" + &text + "\", 0x0";
         Command { text }
     }
 
@@ -35,104 +22,48 @@ impl Command {
         Command { text }
     }
 
-    pub fn create_push_global_var_by_addr(name: String) -> Command {
-        let text = "push dword [v_".to_string() + &name + "]";
-        Command { text }
-    }
-
-    pub fn create_call_func(name: String) -> Command {
-        let text = "call ".to_string() + &name;
-        Command { text }
-    }
-
-    pub fn create_decl_function(name: String) -> Command {
-        let text = "global _".to_string() + &name + "\n_" + &name + ":";
-        Command { text }
-    }
-
-    pub fn create_int_op(op: TokenType) -> Command {
-        let mut text = "pop ebx\n".to_string();
-        text += "pop eax\n";
-
-        text += match op {
-            TokenType::TPlus => "add eax, ebx\n",
-            TokenType::TMinus => "sub eax, ebx\n",
-            TokenType::TMul => "imul eax, ebx\n",
-            TokenType::TAnd => "and eax, ebx\n",
-            TokenType::TOr => "or eax, ebx\n",
-            _ => "",
-        };
-        text += "push eax";
-
+    pub fn create_reserve_var(name: String, size: i32) -> Command {
+        let text = "v_".to_string() + &name + " : times " + &size.to_string() + " db 0";
         Command { text }
     }
 
-    pub fn create_int_unar_op(op: TokenType) -> Command {
-        let mut text = "pop eax\n".to_string();
-
-        text += match op {
-            TokenType::TPlus => "",
-            TokenType::TMinus => "neg eax\n",
-            _ => "",
-        };
-        text += "push eax";
-
+    pub fn create_push_reg(reg: String) -> Command {
+        let text = "push ".to_string() + &reg;
         Command { text }
     }
 
-    pub fn create_float_op(op: TokenType) -> Command {
-        let mut text = match op {
-            TokenType::TPlus => "faddp\n",
-            TokenType::TMinus => "fchs\n",
-            TokenType::TMul => "fmulp\n",
-            TokenType::TShare => "fdivp\n",
-            _ => "",
-        }.to_string();
-        text += "sub esp, 4\n";
-        text += "fstp DWORD [esp]";
-
+    pub fn create_pop_reg(reg: String) -> Command {
+        let text = "pop ".to_string() + &reg;
         Command { text }
     }
 
-    pub fn create_float_unar_op(op: TokenType) -> Command {
-        let mut text = match op {
-            TokenType::TPlus => "",
-            TokenType::TMinus => "fchs\n",
-            _ => "",
-        }.to_string();
-        text += "sub esp, 4\n";
-        text += "fstp DWORD [esp]";
-
+    pub fn create_call_func(name: String) -> Command {
+        let text = "call ".to_string() + &name;
         Command { text }
     }
 
-    pub fn create_assing(name: String) -> Command {
-        let mut text = "pop eax\n".to_string();
-        text += &("mov [v_".to_string() + &name + "], eax\n");
+    pub fn create_decl_function(name: String) -> Command {
+        let text = "global ".to_string() + &name + "\n" + &name + ":";
         Command { text }
     }
 
-    pub fn create_ret(shift: i32) -> Command {
-        let text = "ret ".to_string() + &shift.to_string();
+    pub fn create_extern(name: String) -> Command {
+        let text = "extern ".to_string() + &name;
         Command { text }
     }
 
-    pub fn create_clear_stack(shift: i32) -> Command {
-        let text = "add esp, ".to_string() + &shift.to_string();
+    pub fn create_label(name: String) -> Command {
+        let text = name + ":";
         Command { text }
     }
 
-    pub fn create_push_to_fld() -> Command {
-        let mut text = "fld DWORD [esp]\n".to_string();
-        text += "pop eax\n";
+    pub fn create_jump(label: String) -> Command {
+        let text = "jmp ".to_string() + &label;
         Command { text }
     }
 
-    pub fn create_float_to_double() -> Command {
-        let mut text = "fld DWORD [esp]\n".to_string();
-        text += "pop eax\n";
-        text += "sub esp, 8\n";
-        text += "fstp QWORD [esp]\n";
+    pub fn create_jump_if_no_overflow(label: String) -> Command {
+        let text = "jno ".to_string() + &label;
         Command { text }
     }
 
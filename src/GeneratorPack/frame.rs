@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct VarPlace {
+    pub offset: i32,
+    pub by_ref: bool,
+}
+
+pub struct Frame {
+    vars: HashMap<String, VarPlace>,
+    pub locals_size: i32,
+    pub args_size: i32,
+    word_size: i32,
+}
+
+impl Frame {
+    pub fn new(word_size: i32) -> Frame {
+        Frame {
+            vars: HashMap::new(),
+            locals_size: 0,
+            args_size: 0,
+            word_size,
+        }
+    }
+
+    pub fn add_arg(&mut self, name: String, size: i32, by_ref: bool) {
+        self.vars.insert(
+            name,
+            VarPlace {
+                // над аргументами лежат static link, адрес возврата и сохраненный указатель кадра
+                offset: 3 * self.word_size + self.args_size,
+                by_ref,
+            },
+        );
+        self.args_size += size;
+    }
+
+    pub fn add_local(&mut self, name: String, size: i32) -> i32 {
+        self.locals_size += size;
+        let offset = -self.locals_size;
+        self.vars.insert(
+            name,
+            VarPlace {
+                offset,
+                by_ref: false,
+            },
+        );
+        offset
+    }
+
+    pub fn get_var(&self, name: &str) -> Option<VarPlace> {
+        match self.vars.get(name) {
+            Some(place) => Some(place.clone()),
+            None => None,
+        }
+    }
+}
@@ -1,46 +1,306 @@
 use std::fmt;
-use GeneratorPack::section::*;
-use GeneratorPack::command::*;
+use std::collections::HashMap;
+use GeneratorPack::frame::*;
+use GeneratorPack::target::*;
+use GeneratorPack::checks::*;
+use GeneratorPack::backend::*;
+use GeneratorPack::ir::*;
+use GeneratorPack::nasm::*;
+use DiagnosticPack::*;
 
 pub struct Generator {
-    pub data_section: Section,
-    pub text_section: Section,
+    pub target: Target,
+    pub backend: Box<Backend>,
+    pub short_circuit: bool,
+    pub checks: Checks,
+    // язык сообщений о непройденных проверках
+    pub lang: Lang,
+
+    pub program: IrProgram,
 
     pub last_format: i32,
+    pub last_label: i32,
+
+    loops: Vec<(String, String)>,
+    bool_names: Option<String>,
+    check_failed: Option<String>,
+    check_names: Vec<String>,
+
+    frames: Vec<Frame>,
+    // функции, которые сейчас генерируются; внешняя - main
+    building: Vec<IrFunction>,
+    // (метка объемлющей функции, имя и сигнатура) -> (метка, глубина вложенности)
+    function_labels: HashMap<(String, String), (String, usize)>,
 }
 
 impl Generator {
     pub fn new() -> Generator {
-        let data_section = Section::new(".data".to_string());
-        let text_section = Section::new(".text".to_string());
+        Generator::with_target(Target::Elf32Linux)
+    }
 
+    pub fn with_target(target: Target) -> Generator {
         Generator {
-            data_section,
-            text_section,
+            target,
+            backend: target.backend(),
+            short_circuit: false,
+            checks: Checks::none(),
+            lang: Lang::Ru,
+            program: IrProgram::new(),
             last_format: 0,
+            last_label: 0,
+
+            loops: vec![],
+            bool_names: None,
+            check_failed: None,
+            check_names: vec![],
+
+            frames: vec![],
+            building: vec![],
+            function_labels: HashMap::new(),
         }
     }
 
-    pub fn push_to_data_section(&mut self, command: Command) {
-        self.data_section.push(command);
+    pub fn emit_data(&mut self, data: IrData) {
+        self.program.data.push(data);
+    }
+
+    pub fn emit(&mut self, instr: Instr) {
+        self.building.last_mut().unwrap().push(instr);
     }
 
-    pub fn push_to_text_section(&mut self, command: Command) {
-        self.text_section.push(command);
+    pub fn emit_label(&mut self, label: String) {
+        self.building.last_mut().unwrap().push_label(label);
     }
 
     pub fn next_format(&mut self) -> String {
         self.last_format += 1;
         "fmt_".to_string() + &(self.last_format - 1).to_string()
     }
+
+    pub fn next_label(&mut self) -> String {
+        self.last_label += 1;
+        "l_".to_string() + &(self.last_label - 1).to_string()
+    }
+
+    pub fn bool_names(&mut self) -> String {
+        if let Some(ref label) = self.bool_names {
+            return label.clone();
+        }
+
+        let label = "bool_names".to_string();
+        self.emit_data(IrData::BoolNames(label.clone()));
+        self.bool_names = Some(label.clone());
+        label
+    }
+
+    pub fn check_bounds(&mut self, left: i64, right: i64, line: i32) {
+        if self.checks.bounds {
+            self.push_range_guard("bounds", left, right, line);
+        }
+    }
+
+    pub fn check_range(&mut self, left: i64, right: i64, line: i32) {
+        if self.checks.range {
+            self.push_range_guard("range", left, right, line);
+        }
+    }
+
+    // Вызывается сразу после целочисленной операции, пока флаг OF не испорчен
+    pub fn check_overflow(&mut self, line: i32) {
+        if !self.checks.overflow {
+            return;
+        }
+        let ok_label = self.next_label();
+        self.emit(Instr::JumpIfNoOverflow(ok_label.clone()));
+        self.push_check_failure("overflow", line);
+        self.emit_label(ok_label);
+    }
+
+    fn push_range_guard(&mut self, kind: &str, left: i64, right: i64, line: i32) {
+        let ok_label = self.next_label();
+        let fail_label = self.next_label();
+        self.emit(Instr::RangeGuard {
+            left,
+            right,
+            ok: ok_label.clone(),
+            fail: fail_label.clone(),
+        });
+        self.emit_label(fail_label);
+        self.push_check_failure(kind, line);
+        self.emit_label(ok_label);
+    }
+
+    fn push_check_failure(&mut self, kind: &str, line: i32) {
+        let routine = self.check_failed();
+        let name = "check_".to_string() + kind;
+        if !self.check_names.contains(&name) {
+            self.emit_data(IrData::Str {
+                name: name.clone(),
+                text: kind.to_string(),
+            });
+            self.check_names.push(name.clone());
+        }
+
+        self.emit(Instr::Const(line as i64));
+        self.emit(Instr::LoadAddr(Place::Label(name)));
+        self.emit(Instr::Call(routine));
+    }
+
+    // Печатает вид проверки и строку исходника и завершает программу с кодом 1
+    fn check_failed(&mut self) -> String {
+        if let Some(ref label) = self.check_failed {
+            return label.clone();
+        }
+
+        let label = "check_failed".to_string();
+        let format = self.next_format();
+        let text = format_message("runtime.check_failed", &["%s".to_string(), "%d".to_string()], self.lang);
+        self.emit_data(IrData::Format {
+            name: format.clone(),
+            text,
+        });
+        self.program.externs.push("exit".to_string());
+
+        let mut routine = IrFunction::new(label.clone(), IrFunctionKind::Routine);
+        // адрес возврата не нужен, на вершине остаются имя проверки и номер строки
+        routine.push(Instr::DropReturnAddress);
+        routine.push(Instr::CallPrintf {
+            format,
+            args: vec![IrType::Int, IrType::Int],
+        });
+        routine.push(Instr::CallExit(1));
+        self.program.functions.push(routine);
+
+        self.check_failed = Some(label.clone());
+        label
+    }
+
+    pub fn push_loop(&mut self, continue_label: String, break_label: String) {
+        self.loops.push((continue_label, break_label));
+    }
+
+    pub fn pop_loop(&mut self) {
+        self.loops.pop();
+    }
+
+    pub fn continue_label(&self) -> String {
+        self.loops.last().unwrap().0.clone()
+    }
+
+    pub fn break_label(&self) -> String {
+        self.loops.last().unwrap().1.clone()
+    }
+
+    // Метка функции, объявленной в текущей функции; forward-объявление и тело получают одну метку.
+    // Ключ включает метку объемлющей функции, чтобы вложенные функции с одним именем
+    // в разных родителях не совпали
+    pub fn declare_function(&mut self, name: String, sign: String) -> (String, usize) {
+        let key = (self.building.last().unwrap().label.clone(), name.clone() + &sign);
+        if let Some(res) = self.function_labels.get(&key) {
+            return res.clone();
+        }
+
+        let label = "f_".to_string() + &name + "_" + &self.function_labels.len().to_string();
+        let res = (label, self.frames.len() + 1);
+        self.function_labels.insert(key, res.clone());
+        res
+    }
+
+    // Метка вызываемой функции: ищется от текущей функции наружу, как имя в областях видимости
+    pub fn function_label(&self, name: String, sign: String) -> (String, usize) {
+        let name = name + &sign;
+        for function in self.building.iter().rev() {
+            let key = (function.label.clone(), name.clone());
+            if let Some(res) = self.function_labels.get(&key) {
+                return res.clone();
+            }
+        }
+        unreachable!()
+    }
+
+    pub fn begin_main(&mut self) {
+        self.building.push(IrFunction::new("main".to_string(), IrFunctionKind::Main));
+    }
+
+    // main идет в листинге первой, за ней функции в порядке завершения
+    pub fn end_main(&mut self) {
+        let main = self.building.pop().unwrap();
+        self.program.functions.insert(0, main);
+    }
+
+    pub fn begin_function(&mut self, label: String) {
+        self.frames.push(Frame::new(self.backend.word_size()));
+        self.building.push(IrFunction::new(label, IrFunctionKind::Routine));
+    }
+
+    pub fn end_function(&mut self) {
+        let frame = self.frames.pop().unwrap();
+        let mut function = self.building.pop().unwrap();
+
+        function.kind = IrFunctionKind::Pascal {
+            locals_size: frame.locals_size,
+            args_size: frame.args_size,
+        };
+        self.program.functions.push(function);
+    }
+
+    pub fn add_arg(&mut self, name: String, size: i32, by_ref: bool) {
+        self.frames.last_mut().unwrap().add_arg(name, size, by_ref);
+    }
+
+    pub fn add_local(&mut self, name: String, size: i32) -> Option<i32> {
+        match self.frames.last_mut() {
+            Some(frame) => Some(frame.add_local(name, size)),
+            None => None,
+        }
+    }
+
+    // Место переменной: ищем от текущего кадра к внешним, иначе глобальная
+    pub fn var_address(&self, name: String) -> Place {
+        let depth = self.frames.len();
+        for i in (0..depth).rev() {
+            let place = match self.frames[i].get_var(&name) {
+                Some(place) => place,
+                None => continue,
+            };
+
+            let up = depth - (i + 1);
+            let offset = place.offset;
+            if place.by_ref {
+                return Place::Ref { up, offset };
+            }
+            return Place::Frame { up, offset };
+        }
+
+        Place::Global(name)
+    }
+
+    pub fn value_size(&self, is_double: bool) -> i32 {
+        if is_double {
+            self.backend.float_size()
+        } else {
+            self.backend.word_size()
+        }
+    }
+
+    pub fn symbol(&self, name: &str) -> String {
+        self.target.symbol(name)
+    }
+
+    pub fn push_static_link(&mut self, callee_depth: usize) {
+        let parent = callee_depth - 1;
+        let depth = self.frames.len();
+
+        if parent == 0 {
+            self.emit(Instr::Const(0));
+        } else {
+            self.emit(Instr::PushFrame(depth - parent));
+        }
+    }
 }
 
 impl fmt::Display for Generator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "extern _printf \n{} \n{}",
-            self.data_section, self.text_section
-        )
+        write!(f, "{}", NasmEmitter::new(self.target).emit(&self.program))
     }
 }
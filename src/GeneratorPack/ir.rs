@@ -0,0 +1,357 @@
+use std::fmt;
+use TokenizerPack::*;
+
+// Промежуточное представление: стековая машина с явными типами значений.
+// Генератор строит его по дереву, ассемблер получается из него отдельным проходом.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IrType {
+    Int,
+    Double,
+}
+
+// Место в памяти, из которого читают или в которое пишут
+#[derive(Clone, PartialEq)]
+pub enum Place {
+    // глобальная переменная программы
+    Global(String),
+    // метка в секции данных
+    Label(String),
+    // переменная в кадре, up - на сколько уровней static link подняться
+    Frame { up: usize, offset: i32 },
+    // var-аргумент: в кадре лежит адрес переменной
+    Ref { up: usize, offset: i32 },
+    // адрес снимается с вершины стека
+    Stack,
+}
+
+#[derive(Clone, PartialEq)]
+pub enum Instr {
+    Const(i64),
+    ConstDouble(f64),
+    Load(IrType, Place),
+    LoadAddr(Place),
+    Store(IrType, Place),
+    // значение функции перед возвратом
+    LoadResult(IrType, Place),
+    // значение функции после вызова
+    PushResult(IrType),
+    Drop(IrType),
+
+    Binary(IrType, TokenType),
+    Unary(IrType, TokenType),
+    Compare(IrType, TokenType),
+    AddConst(i64),
+    IndexOffset { left: i64, stride: i64 },
+    BoolNot,
+    BoolToStr(String),
+    IntToDouble,
+    // подготовка операнда вещественной операции
+    ToFloatUnit(IrType),
+
+    Jump(String),
+    JumpIfFalse(String),
+    JumpIfTrue(String),
+    CompareJumpIfFalse(IrType, TokenType, String),
+    JumpIfNoOverflow(String),
+    ForInit { place: Place, end: String },
+    ForStep { place: Place, body: String, end: String },
+    // значение на вершине стека остается на месте
+    RangeGuard { left: i64, right: i64, ok: String, fail: String },
+
+    // адрес кадра для static link вызываемой функции
+    PushFrame(usize),
+    Call(String),
+    CallPrintf { format: String, args: Vec<IrType> },
+    CallExit(i32),
+    DropReturnAddress,
+}
+
+impl Instr {
+    // После такой инструкции управление может уйти из блока
+    pub fn is_terminator(&self) -> bool {
+        match *self {
+            Instr::Jump(..)
+            | Instr::JumpIfFalse(..)
+            | Instr::JumpIfTrue(..)
+            | Instr::CompareJumpIfFalse(..)
+            | Instr::JumpIfNoOverflow(..)
+            | Instr::ForInit { .. }
+            | Instr::ForStep { .. }
+            | Instr::RangeGuard { .. }
+            | Instr::CallExit(..) => true,
+            _ => false,
+        }
+    }
+}
+
+// Ячейка составной переменной с начальным значением
+#[derive(Clone, PartialEq)]
+pub enum IrValue {
+    Int(i64),
+    Double(f64),
+}
+
+#[derive(Clone, PartialEq)]
+pub enum IrData {
+    Int { name: String, value: i64 },
+    Double { name: String, value: f64 },
+    Bytes { name: String, size: i32 },
+    Words { name: String, items: Vec<IrValue> },
+    Format { name: String, text: String },
+    Str { name: String, text: String },
+    BoolNames(String),
+}
+
+#[derive(Clone, PartialEq)]
+pub enum IrFunctionKind {
+    Main,
+    Pascal { locals_size: i32, args_size: i32 },
+    // вспомогательная процедура без кадра
+    Routine,
+}
+
+pub struct IrBlock {
+    pub label: Option<String>,
+    pub instrs: Vec<Instr>,
+}
+
+pub struct IrFunction {
+    pub label: String,
+    pub kind: IrFunctionKind,
+    pub blocks: Vec<IrBlock>,
+}
+
+impl IrFunction {
+    pub fn new(label: String, kind: IrFunctionKind) -> IrFunction {
+        IrFunction {
+            label,
+            kind,
+            blocks: vec![],
+        }
+    }
+
+    pub fn push(&mut self, instr: Instr) {
+        let need_block = match self.blocks.last() {
+            Some(block) => match block.instrs.last() {
+                Some(last) => last.is_terminator(),
+                None => false,
+            },
+            None => true,
+        };
+        if need_block {
+            self.blocks.push(IrBlock {
+                label: None,
+                instrs: vec![],
+            });
+        }
+        self.blocks.last_mut().unwrap().instrs.push(instr);
+    }
+
+    pub fn push_label(&mut self, label: String) {
+        self.blocks.push(IrBlock {
+            label: Some(label),
+            instrs: vec![],
+        });
+    }
+}
+
+pub struct IrProgram {
+    pub externs: Vec<String>,
+    pub data: Vec<IrData>,
+    pub functions: Vec<IrFunction>,
+}
+
+impl IrProgram {
+    pub fn new() -> IrProgram {
+        IrProgram {
+            externs: vec!["printf".to_string()],
+            data: vec![],
+            functions: vec![],
+        }
+    }
+}
+
+pub fn op_name(op: &TokenType) -> &'static str {
+    match *op {
+        TokenType::TPlus => "+",
+        TokenType::TMinus => "-",
+        TokenType::TMul => "*",
+        TokenType::TShare => "/",
+        TokenType::TDiv => "div",
+        TokenType::TMod => "mod",
+        TokenType::TAnd => "and",
+        TokenType::TOr => "or",
+        TokenType::TNot => "not",
+        TokenType::TGe => ">=",
+        TokenType::TGt => ">",
+        TokenType::TEq => "=",
+        TokenType::TLe => "<=",
+        TokenType::TLt => "<",
+        TokenType::TNe => "<>",
+        _ => "?",
+    }
+}
+
+impl fmt::Display for IrType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IrType::Int => write!(f, "i"),
+            IrType::Double => write!(f, "d"),
+        }
+    }
+}
+
+fn frame_name(up: usize) -> String {
+    if up == 0 {
+        "fp".to_string()
+    } else {
+        "fp^".to_string() + &up.to_string()
+    }
+}
+
+fn offset_name(offset: i32) -> String {
+    if offset < 0 {
+        offset.to_string()
+    } else {
+        "+".to_string() + &offset.to_string()
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Place::Global(ref name) => write!(f, "@{}", name),
+            Place::Label(ref name) => write!(f, "{}", name),
+            Place::Frame { up, offset } => write!(f, "[{}{}]", frame_name(up), offset_name(offset)),
+            Place::Ref { up, offset } => write!(f, "[[{}{}]]", frame_name(up), offset_name(offset)),
+            Place::Stack => write!(f, "[pop]"),
+        }
+    }
+}
+
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Instr::Const(v) => write!(f, "push.i {}", v),
+            Instr::ConstDouble(v) => write!(f, "push.d {:?}", v),
+            Instr::Load(t, ref place) => write!(f, "load.{} {}", t, place),
+            Instr::LoadAddr(ref place) => write!(f, "addr {}", place),
+            Instr::Store(t, ref place) => write!(f, "store.{} {}", t, place),
+            Instr::LoadResult(t, ref place) => write!(f, "result.{} {}", t, place),
+            Instr::PushResult(t) => write!(f, "push.result.{}", t),
+            Instr::Drop(t) => write!(f, "drop.{}", t),
+            Instr::Binary(t, ref op) => write!(f, "op.{} {}", t, op_name(op)),
+            Instr::Unary(t, ref op) => write!(f, "unary.{} {}", t, op_name(op)),
+            Instr::Compare(t, ref op) => write!(f, "cmp.{} {}", t, op_name(op)),
+            Instr::AddConst(v) => write!(f, "add.i {}", v),
+            Instr::IndexOffset { left, stride } => write!(f, "index {}, {}", left, stride),
+            Instr::BoolNot => write!(f, "not.b"),
+            Instr::BoolToStr(ref label) => write!(f, "bool.str {}", label),
+            Instr::IntToDouble => write!(f, "i2d"),
+            Instr::ToFloatUnit(t) => write!(f, "fpu.{}", t),
+            Instr::Jump(ref label) => write!(f, "jmp {}", label),
+            Instr::JumpIfFalse(ref label) => write!(f, "jz {}", label),
+            Instr::JumpIfTrue(ref label) => write!(f, "jnz {}", label),
+            Instr::CompareJumpIfFalse(t, ref op, ref label) => {
+                write!(f, "cmp.{} {} else {}", t, op_name(op), label)
+            }
+            Instr::JumpIfNoOverflow(ref label) => write!(f, "jno {}", label),
+            Instr::ForInit { ref place, ref end } => write!(f, "for.init {} else {}", place, end),
+            Instr::ForStep {
+                ref place,
+                ref body,
+                ref end,
+            } => write!(f, "for.step {} {} else {}", place, body, end),
+            Instr::RangeGuard {
+                left,
+                right,
+                ref ok,
+                ref fail,
+            } => write!(f, "guard {}..{} {} else {}", left, right, ok, fail),
+            Instr::PushFrame(up) => write!(f, "push.frame {}", frame_name(up)),
+            Instr::Call(ref label) => write!(f, "call {}", label),
+            Instr::CallPrintf {
+                ref format,
+                ref args,
+            } => {
+                let args: Vec<String> = args.iter().map(|t| t.to_string()).collect();
+                write!(f, "printf {} ({})", format, args.join(", "))
+            }
+            Instr::CallExit(code) => write!(f, "exit {}", code),
+            Instr::DropReturnAddress => write!(f, "drop.ret"),
+        }
+    }
+}
+
+impl fmt::Display for IrValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IrValue::Int(v) => write!(f, "i {}", v),
+            IrValue::Double(v) => write!(f, "d {:?}", v),
+        }
+    }
+}
+
+impl fmt::Display for IrData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IrData::Int { ref name, value } => write!(f, "@{} : i = {}", name, value),
+            IrData::Double { ref name, value } => write!(f, "@{} : d = {:?}", name, value),
+            IrData::Bytes { ref name, size } => write!(f, "@{} : bytes {}", name, size),
+            IrData::Words { ref name, ref items } => {
+                let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                write!(f, "@{} : words [{}]", name, items.join(", "))
+            }
+            IrData::Format { ref name, ref text } => write!(f, "{} : format {:?}", name, text),
+            IrData::Str { ref name, ref text } => write!(f, "{} : str {:?}", name, text),
+            IrData::BoolNames(ref name) => write!(f, "{} : bool names", name),
+        }
+    }
+}
+
+impl fmt::Display for IrFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            IrFunctionKind::Main => try!(writeln!(f, "main {}:", self.label)),
+            IrFunctionKind::Pascal {
+                locals_size,
+                args_size,
+            } => try!(writeln!(
+                f,
+                "function {} (args {}, locals {}):",
+                self.label,
+                args_size,
+                locals_size
+            )),
+            IrFunctionKind::Routine => try!(writeln!(f, "routine {}:", self.label)),
+        }
+
+        for (i, block) in self.blocks.iter().enumerate() {
+            match block.label {
+                Some(ref label) => try!(writeln!(f, "{}:", label)),
+                None => try!(writeln!(f, ".b{}:", i)),
+            }
+            for instr in &block.instrs {
+                try!(writeln!(f, "    {}", instr));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for IrProgram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for name in &self.externs {
+            try!(writeln!(f, "extern {}", name));
+        }
+        try!(writeln!(f, ""));
+        for data in &self.data {
+            try!(writeln!(f, "{}", data));
+        }
+        for function in &self.functions {
+            try!(writeln!(f, ""));
+            try!(write!(f, "{}", function));
+        }
+        Ok(())
+    }
+}
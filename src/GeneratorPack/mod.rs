@@ -1,7 +1,21 @@
 pub mod generator;
 pub mod section;
 pub mod command;
+pub mod frame;
+pub mod target;
+pub mod checks;
+pub mod backend;
+pub mod x86;
+pub mod x86_64;
+pub mod ir;
+pub mod nasm;
 
 pub use self::generator::*;
 pub use self::section::*;
 pub use self::command::*;
+pub use self::frame::*;
+pub use self::target::*;
+pub use self::checks::*;
+pub use self::backend::*;
+pub use self::ir::*;
+pub use self::nasm::*;
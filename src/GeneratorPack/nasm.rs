@@ -0,0 +1,253 @@
+use GeneratorPack::section::*;
+use GeneratorPack::command::*;
+use GeneratorPack::target::*;
+use GeneratorPack::backend::*;
+use GeneratorPack::ir::*;
+
+// Переводит промежуточное представление в листинг nasm для выбранной платформы
+pub struct NasmEmitter {
+    target: Target,
+    backend: Box<Backend>,
+}
+
+impl NasmEmitter {
+    pub fn new(target: Target) -> NasmEmitter {
+        NasmEmitter {
+            target,
+            backend: target.backend(),
+        }
+    }
+
+    pub fn emit(&self, program: &IrProgram) -> String {
+        let mut data_section = Section::new(".data".to_string());
+        for data in &program.data {
+            data_section.push(self.lower_data(data));
+        }
+
+        let mut text_section = Section::new(".text".to_string());
+        for function in &program.functions {
+            for command in self.lower_function(function) {
+                if !command.as_str().is_empty() {
+                    text_section.push(command);
+                }
+            }
+        }
+
+        let mut ans = "".to_string();
+        for directive in self.target.directives() {
+            ans += &directive;
+            ans += "\n";
+        }
+        let externs: Vec<String> = program
+            .externs
+            .iter()
+            .map(|name| Command::create_extern(self.target.symbol(name)).as_str())
+            .collect();
+        ans += &format!("{} \n{} \n{}", externs.join("\n"), data_section, text_section);
+        for section in self.target.extra_sections() {
+            ans += &format!("\n{}", Section::new(section));
+        }
+        ans
+    }
+
+    fn lower_data(&self, data: &IrData) -> Command {
+        match *data {
+            IrData::Int { ref name, value } => self.backend.create_int_var(name.clone(), value),
+            IrData::Double { ref name, value } => {
+                self.backend.create_float_var(name.clone(), value)
+            }
+            IrData::Bytes { ref name, size } => Command::create_reserve_var(name.clone(), size),
+            IrData::Words { ref name, ref items } => {
+                // целое занимает слово, double - 8 байт, как в get_storage_size
+                let int_directive = if self.backend.word_size() == 8 { "dq" } else { "dd" };
+                let words: Vec<String> = items
+                    .iter()
+                    .map(|item| match *item {
+                        IrValue::Int(v) => format!("{} {}", int_directive, v),
+                        IrValue::Double(v) => format!("dq 0x{:x}", v.to_bits()),
+                    })
+                    .collect();
+                Command::new(format!("v_{} : {}", name, words.join("\n    ")))
+            }
+            IrData::Format { ref name, ref text } => {
+                Command::create_format_string(name.clone(), text.clone())
+            }
+            IrData::Str { ref name, ref text } => {
+                Command::create_string(name.clone(), text.clone())
+            }
+            IrData::BoolNames(ref name) => self.backend.create_bool_names(name.clone()),
+        }
+    }
+
+    fn lower_function(&self, function: &IrFunction) -> Vec<Command> {
+        let mut commands = vec![];
+        match function.kind {
+            IrFunctionKind::Main => {
+                let symbol = self.target.symbol(&function.label);
+                commands.push(self.backend.create_main_entry(symbol));
+            }
+            IrFunctionKind::Pascal { locals_size, .. } => {
+                commands.push(Command::create_label(function.label.clone()));
+                commands.push(self.backend.create_enter(locals_size));
+            }
+            IrFunctionKind::Routine => commands.push(Command::create_label(function.label.clone())),
+        }
+
+        for block in &function.blocks {
+            if let Some(ref label) = block.label {
+                commands.push(Command::create_label(label.clone()));
+            }
+            for instr in &block.instrs {
+                self.lower_instr(instr, &mut commands);
+            }
+        }
+
+        match function.kind {
+            IrFunctionKind::Main => commands.push(self.backend.create_main_exit()),
+            IrFunctionKind::Pascal { args_size, .. } => {
+                let shift = args_size + self.backend.word_size();
+                commands.push(self.backend.create_leave(shift));
+            }
+            IrFunctionKind::Routine => {}
+        }
+        commands
+    }
+
+    // Возвращает операнд для [] и добавляет подготовку ecx, если она нужна
+    fn lower_place(&self, place: &Place, commands: &mut Vec<Command>) -> String {
+        let (up, offset, by_ref) = match *place {
+            Place::Global(ref name) => return "v_".to_string() + name,
+            Place::Label(ref name) => return name.clone(),
+            Place::Stack => {
+                let reg = self.backend.link_reg();
+                commands.push(Command::create_pop_reg(reg.clone()));
+                return reg;
+            }
+            Place::Frame { up, offset } => (up, offset, false),
+            Place::Ref { up, offset } => (up, offset, true),
+        };
+
+        let mut base = self.backend.frame_reg();
+        if up > 0 {
+            commands.push(self.backend.create_static_link_walk(up));
+            base = self.backend.link_reg();
+        }
+
+        let addr = if offset < 0 {
+            base + &offset.to_string()
+        } else {
+            base + "+" + &offset.to_string()
+        };
+
+        if by_ref {
+            commands.push(self.backend.create_load_ref(addr));
+            return self.backend.link_reg();
+        }
+        addr
+    }
+
+    fn value_size(&self, value_type: IrType) -> i32 {
+        match value_type {
+            IrType::Int => self.backend.word_size(),
+            IrType::Double => self.backend.float_size(),
+        }
+    }
+
+    fn lower_instr(&self, instr: &Instr, commands: &mut Vec<Command>) {
+        let backend = &self.backend;
+        let command = match *instr {
+            Instr::Const(value) => backend.create_push_const(value),
+            Instr::ConstDouble(value) => backend.create_push_float_const(value),
+            Instr::Load(value_type, ref place) => {
+                let addr = self.lower_place(place, commands);
+                match value_type {
+                    IrType::Int => backend.create_push_var(addr),
+                    IrType::Double => backend.create_push_float_var(addr),
+                }
+            }
+            Instr::LoadAddr(ref place) => {
+                let addr = self.lower_place(place, commands);
+                backend.create_push_var_addr(addr)
+            }
+            Instr::Store(value_type, ref place) => {
+                let addr = self.lower_place(place, commands);
+                match value_type {
+                    IrType::Int => backend.create_assign(addr),
+                    IrType::Double => backend.create_float_assign(addr),
+                }
+            }
+            Instr::LoadResult(value_type, ref place) => {
+                let addr = self.lower_place(place, commands);
+                match value_type {
+                    IrType::Int => backend.create_load_int_result(addr),
+                    IrType::Double => backend.create_load_float_result(addr),
+                }
+            }
+            Instr::PushResult(IrType::Int) => backend.create_push_int_result(),
+            Instr::PushResult(IrType::Double) => backend.create_push_float_result(),
+            Instr::Drop(value_type) => backend.create_clear_stack(self.value_size(value_type)),
+
+            Instr::Binary(IrType::Int, ref op) => backend.create_int_op(op.clone()),
+            Instr::Binary(IrType::Double, ref op) => backend.create_float_op(op.clone()),
+            Instr::Unary(IrType::Int, ref op) => backend.create_int_unar_op(op.clone()),
+            Instr::Unary(IrType::Double, ref op) => backend.create_float_unar_op(op.clone()),
+            Instr::Compare(IrType::Int, ref op) => backend.create_int_cmp(op.clone()),
+            Instr::Compare(IrType::Double, ref op) => backend.create_float_cmp(op.clone()),
+            Instr::AddConst(value) => backend.create_int_add_const(value),
+            Instr::IndexOffset { left, stride } => backend.create_index_offset(left, stride),
+            Instr::BoolNot => backend.create_bool_not(),
+            Instr::BoolToStr(ref label) => backend.create_bool_to_str(label.clone()),
+            Instr::IntToDouble => backend.create_int_to_float(),
+            Instr::ToFloatUnit(IrType::Int) => backend.create_push_int_to_fld(),
+            Instr::ToFloatUnit(IrType::Double) => backend.create_push_to_fld(),
+
+            Instr::Jump(ref label) => Command::create_jump(label.clone()),
+            Instr::JumpIfFalse(ref label) => backend.create_jump_if_false(label.clone()),
+            Instr::JumpIfTrue(ref label) => backend.create_jump_if_true(label.clone()),
+            Instr::CompareJumpIfFalse(IrType::Int, ref op, ref label) => {
+                backend.create_int_cmp_jump_if_false(op.clone(), label.clone())
+            }
+            Instr::CompareJumpIfFalse(IrType::Double, ref op, ref label) => {
+                backend.create_float_cmp_jump_if_false(op.clone(), label.clone())
+            }
+            Instr::JumpIfNoOverflow(ref label) => Command::create_jump_if_no_overflow(label.clone()),
+            Instr::ForInit { ref place, ref end } => {
+                let addr = self.lower_place(place, commands);
+                backend.create_for_init(addr, end.clone())
+            }
+            Instr::ForStep {
+                ref place,
+                ref body,
+                ref end,
+            } => {
+                let addr = self.lower_place(place, commands);
+                backend.create_for_step(addr, body.clone(), end.clone())
+            }
+            Instr::RangeGuard {
+                left,
+                right,
+                ref ok,
+                ref fail,
+            } => backend.create_range_guard(left, right, ok.clone(), fail.clone()),
+
+            Instr::PushFrame(0) => Command::create_push_reg(backend.frame_reg()),
+            Instr::PushFrame(up) => {
+                commands.push(backend.create_static_link_walk(up));
+                Command::create_push_reg(backend.link_reg())
+            }
+            Instr::Call(ref label) => Command::create_call_func(label.clone()),
+            Instr::CallPrintf {
+                ref format,
+                ref args,
+            } => {
+                let doubles: Vec<bool> = args.iter().map(|t| *t == IrType::Double).collect();
+                let symbol = self.target.symbol("printf");
+                backend.create_call_printf(symbol, format.clone(), &doubles)
+            }
+            Instr::CallExit(code) => backend.create_call_exit(self.target.symbol("exit"), code),
+            // адрес возврата не нужен, на вершине остаются аргументы
+            Instr::DropReturnAddress => Command::create_pop_reg(backend.link_reg()),
+        };
+        commands.push(command);
+    }
+}
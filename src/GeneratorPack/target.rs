@@ -0,0 +1,76 @@
+use GeneratorPack::backend::*;
+use GeneratorPack::x86::*;
+use GeneratorPack::x86_64::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Target {
+    Elf32Linux,
+    Elf64Linux,
+    Win32,
+    Macho32,
+}
+
+impl Target {
+    pub fn from_name(name: &str) -> Option<Target> {
+        match name {
+            "elf32-linux" => Some(Target::Elf32Linux),
+            "elf64-linux" => Some(Target::Elf64Linux),
+            "win32" => Some(Target::Win32),
+            "macho32" => Some(Target::Macho32),
+            _ => None,
+        }
+    }
+
+    // Формат объектного файла для nasm -f
+    pub fn object_format(&self) -> &'static str {
+        match *self {
+            Target::Elf32Linux => "elf32",
+            Target::Elf64Linux => "elf64",
+            Target::Win32 => "win32",
+            Target::Macho32 => "macho32",
+        }
+    }
+
+    // Флаг разрядности для gcc при компоновке
+    pub fn machine_flag(&self) -> &'static str {
+        match *self {
+            Target::Elf64Linux => "-m64",
+            Target::Elf32Linux | Target::Win32 | Target::Macho32 => "-m32",
+        }
+    }
+
+    pub fn backend(&self) -> Box<Backend> {
+        match *self {
+            Target::Elf64Linux => Box::new(X86_64),
+            Target::Elf32Linux | Target::Win32 | Target::Macho32 => Box::new(X86),
+        }
+    }
+
+    // Директивы в начале листинга
+    pub fn directives(&self) -> Vec<String> {
+        match *self {
+            // адресация относительно rip нужна для PIE
+            Target::Elf64Linux => vec!["bits 64".to_string(), "default rel".to_string()],
+            Target::Elf32Linux | Target::Win32 | Target::Macho32 => vec![],
+        }
+    }
+
+    // Имя символа C в объектном файле: cdecl на Windows и macOS добавляет _
+    pub fn symbol(&self, name: &str) -> String {
+        match *self {
+            Target::Elf32Linux | Target::Elf64Linux => name.to_string(),
+            Target::Win32 | Target::Macho32 => "_".to_string() + name,
+        }
+    }
+
+    // Секции, которые добавляются в конец листинга
+    pub fn extra_sections(&self) -> Vec<String> {
+        match *self {
+            // без этой секции ld считает стек исполняемым
+            Target::Elf32Linux | Target::Elf64Linux => {
+                vec![".note.GNU-stack noalloc noexec nowrite progbits".to_string()]
+            }
+            Target::Win32 | Target::Macho32 => vec![],
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use TokenizerPack::*;
+use GeneratorPack::command::*;
+use GeneratorPack::backend::*;
+
+pub struct X86;
+
+impl Backend for X86 {
+    fn word_size(&self) -> i32 {
+        4
+    }
+
+    fn float_size(&self) -> i32 {
+        8
+    }
+
+    fn frame_reg(&self) -> String {
+        "ebp".to_string()
+    }
+
+    fn link_reg(&self) -> String {
+        "ecx".to_string()
+    }
+
+    fn int_format(&self) -> String {
+        "%d".to_string()
+    }
+
+    fn create_main_entry(&self, symbol: String) -> Command {
+        Command::create_decl_function(symbol)
+    }
+
+    fn create_main_exit(&self) -> Command {
+        let mut text = "mov eax, 0\n".to_string();
+        text += "ret";
+        Command::new(text)
+    }
+
+    fn create_int_var(&self, name: String, value: i64) -> Command {
+        let text = "v_".to_string() + &name + " : dd " + &value.to_string();
+        Command::new(text)
+    }
+
+    fn create_float_var(&self, name: String, value: f64) -> Command {
+        let text = format!("v_{} : dq 0x{:x}", name, value.to_bits());
+        Command::new(text)
+    }
+
+    fn create_push_const(&self, value: i64) -> Command {
+        let text = "push ".to_string() + &(value as i32).to_string();
+        Command::new(text)
+    }
+
+    fn create_push_float_const(&self, value: f64) -> Command {
+        let bits = value.to_bits();
+        let mut text = "push ".to_string() + &((bits >> 32) as i32).to_string() + "\n";
+        text += &("push ".to_string() + &(bits as i32).to_string());
+        Command::new(text)
+    }
+
+    fn create_push_var(&self, addr: String) -> Command {
+        let text = "push dword [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_push_float_var(&self, addr: String) -> Command {
+        let mut text = "push dword [".to_string() + &addr + "+4]\n";
+        text += &("push dword [".to_string() + &addr + "]");
+        Command::new(text)
+    }
+
+    fn create_push_var_addr(&self, addr: String) -> Command {
+        let mut text = "lea eax, [".to_string() + &addr + "]\n";
+        text += "push eax";
+        Command::new(text)
+    }
+
+    fn create_push_int_result(&self) -> Command {
+        Command::create_push_reg("eax".to_string())
+    }
+
+    fn create_int_op(&self, op: TokenType) -> Command {
+        let mut text = "pop ebx\n".to_string();
+        text += "pop eax\n";
+
+        text += match op {
+            TokenType::TPlus => "add eax, ebx\n",
+            TokenType::TMinus => "sub eax, ebx\n",
+            TokenType::TMul => "imul eax, ebx\n",
+            TokenType::TDiv => "cdq\nidiv ebx\n",
+            TokenType::TMod => "cdq\nidiv ebx\nmov eax, edx\n",
+            TokenType::TAnd => "and eax, ebx\n",
+            TokenType::TOr => "or eax, ebx\n",
+            _ => "",
+        };
+        text += "push eax";
+
+        Command::new(text)
+    }
+
+    fn create_int_unar_op(&self, op: TokenType) -> Command {
+        let mut text = "pop eax\n".to_string();
+
+        text += match op {
+            TokenType::TPlus => "",
+            TokenType::TMinus => "neg eax\n",
+            TokenType::TNot => "not eax\n",
+            _ => "",
+        };
+        text += "push eax";
+
+        Command::new(text)
+    }
+
+    fn create_int_add_const(&self, value: i64) -> Command {
+        let text = "add dword [esp], ".to_string() + &value.to_string();
+        Command::new(text)
+    }
+
+    fn create_index_offset(&self, left: i64, stride: i64) -> Command {
+        let mut text = "pop eax\n".to_string();
+        if left != 0 {
+            text += &("sub eax, ".to_string() + &left.to_string() + "\n");
+        }
+        text += &("imul eax, eax, ".to_string() + &stride.to_string() + "\n");
+        text += "add [esp], eax";
+        Command::new(text)
+    }
+
+    fn create_bool_not(&self) -> Command {
+        Command::new("xor dword [esp], 1".to_string())
+    }
+
+    fn create_float_op(&self, op: TokenType) -> Command {
+        let mut text = match op {
+            TokenType::TPlus => "faddp\n",
+            // левый операнд в st0, правый в st1
+            TokenType::TMinus => "fsubrp\n",
+            TokenType::TMul => "fmulp\n",
+            TokenType::TShare => "fdivrp\n",
+            _ => "",
+        }.to_string();
+        text += "sub esp, 8\n";
+        text += "fstp QWORD [esp]";
+
+        Command::new(text)
+    }
+
+    fn create_float_unar_op(&self, op: TokenType) -> Command {
+        let mut text = match op {
+            TokenType::TPlus => "",
+            TokenType::TMinus => "fchs\n",
+            _ => "",
+        }.to_string();
+        text += "sub esp, 8\n";
+        text += "fstp QWORD [esp]";
+
+        Command::new(text)
+    }
+
+    fn create_assign(&self, addr: String) -> Command {
+        let mut text = "pop eax\n".to_string();
+        text += &("mov [".to_string() + &addr + "], eax");
+        Command::new(text)
+    }
+
+    fn create_float_assign(&self, addr: String) -> Command {
+        let mut text = "pop eax\n".to_string();
+        text += &("mov [".to_string() + &addr + "], eax\n");
+        text += "pop eax\n";
+        text += &("mov [".to_string() + &addr + "+4], eax");
+        Command::new(text)
+    }
+
+    fn create_enter(&self, locals_size: i32) -> Command {
+        let mut text = "push ebp\n".to_string();
+        text += "mov ebp, esp";
+        if locals_size > 0 {
+            text += &("\nsub esp, ".to_string() + &locals_size.to_string());
+        }
+        Command::new(text)
+    }
+
+    fn create_leave(&self, shift: i32) -> Command {
+        let mut text = "mov esp, ebp\n".to_string();
+        text += "pop ebp\n";
+        text += &("ret ".to_string() + &shift.to_string());
+        Command::new(text)
+    }
+
+    fn create_static_link_walk(&self, count: usize) -> Command {
+        let mut text = "mov ecx, ebp".to_string();
+        for _i in 0..count {
+            text += "\nmov ecx, [ecx+8]";
+        }
+        Command::new(text)
+    }
+
+    fn create_load_ref(&self, addr: String) -> Command {
+        let text = "mov ecx, [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_load_int_result(&self, addr: String) -> Command {
+        let text = "mov eax, [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_load_float_result(&self, addr: String) -> Command {
+        let text = "fld QWORD [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_push_float_result(&self) -> Command {
+        let mut text = "sub esp, 8\n".to_string();
+        text += "fstp QWORD [esp]";
+        Command::new(text)
+    }
+
+    fn create_int_to_float(&self) -> Command {
+        let mut text = "fild DWORD [esp]\n".to_string();
+        text += "sub esp, 4\n";
+        text += "fstp QWORD [esp]";
+        Command::new(text)
+    }
+
+    fn create_clear_stack(&self, shift: i32) -> Command {
+        let text = "add esp, ".to_string() + &shift.to_string();
+        Command::new(text)
+    }
+
+    fn create_push_to_fld(&self) -> Command {
+        let mut text = "fld QWORD [esp]\n".to_string();
+        text += "add esp, 8";
+        Command::new(text)
+    }
+
+    fn create_push_int_to_fld(&self) -> Command {
+        let mut text = "fild DWORD [esp]\n".to_string();
+        text += "add esp, 4";
+        Command::new(text)
+    }
+
+    fn create_int_cmp(&self, op: TokenType) -> Command {
+        let mut text = "pop ebx\n".to_string();
+        text += "pop eax\n";
+        text += "cmp eax, ebx\n";
+        text += &(int_setcc(op) + " al\n");
+        text += "movzx eax, al\n";
+        text += "push eax";
+        Command::new(text)
+    }
+
+    fn create_float_cmp(&self, op: TokenType) -> Command {
+        let mut text = "fcomip st0, st1\n".to_string();
+        text += "fstp st0\n";
+        text += &(float_setcc(op) + " al\n");
+        text += "movzx eax, al\n";
+        text += "push eax";
+        Command::new(text)
+    }
+
+    fn create_jump_if_false(&self, label: String) -> Command {
+        let mut text = "pop eax\n".to_string();
+        text += "cmp eax, 0\n";
+        text += &("je ".to_string() + &label);
+        Command::new(text)
+    }
+
+    fn create_jump_if_true(&self, label: String) -> Command {
+        let mut text = "pop eax\n".to_string();
+        text += "cmp eax, 0\n";
+        text += &("jne ".to_string() + &label);
+        Command::new(text)
+    }
+
+    fn create_int_cmp_jump_if_false(&self, op: TokenType, label: String) -> Command {
+        let mut text = "pop ebx\n".to_string();
+        text += "pop eax\n";
+        text += "cmp eax, ebx\n";
+
+        text += match op {
+            TokenType::TGe => "jl ",
+            TokenType::TGt => "jle ",
+            TokenType::TEq => "jne ",
+            TokenType::TLe => "jg ",
+            TokenType::TLt => "jge ",
+            TokenType::TNe => "je ",
+            _ => "jmp ",
+        };
+        text += &label;
+
+        Command::new(text)
+    }
+
+    fn create_float_cmp_jump_if_false(&self, op: TokenType, label: String) -> Command {
+        let mut text = "fcomip st0, st1\n".to_string();
+        text += "fstp st0\n";
+
+        text += match op {
+            TokenType::TGe => "jb ",
+            TokenType::TGt => "jbe ",
+            TokenType::TEq => "jne ",
+            TokenType::TLe => "ja ",
+            TokenType::TLt => "jae ",
+            TokenType::TNe => "je ",
+            _ => "jmp ",
+        };
+        text += &label;
+
+        Command::new(text)
+    }
+
+    fn create_for_init(&self, addr: String, end_label: String) -> Command {
+        let mut text = "pop ebx\n".to_string();
+        text += "pop eax\n";
+        text += &("mov [".to_string() + &addr + "], eax\n");
+        text += "push ebx\n";
+        text += "cmp eax, ebx\n";
+        text += &("jg ".to_string() + &end_label);
+        Command::new(text)
+    }
+
+    fn create_for_step(&self, addr: String, body_label: String, end_label: String) -> Command {
+        let mut text = "mov eax, [".to_string() + &addr + "]\n";
+        text += "cmp eax, [esp]\n";
+        text += &("jge ".to_string() + &end_label + "\n");
+        text += &("inc dword [".to_string() + &addr + "]\n");
+        text += &("jmp ".to_string() + &body_label);
+        Command::new(text)
+    }
+
+    fn create_bool_names(&self, label: String) -> Command {
+        let mut text = label.clone() + "_false : db \"FALSE\", 0x0\n";
+        text += &(label.clone() + "_true : db \"TRUE\", 0x0\n");
+        text += &(label.clone() + " : dd " + &label + "_false, " + &label + "_true");
+        Command::new(text)
+    }
+
+    fn create_bool_to_str(&self, label: String) -> Command {
+        let mut text = "pop eax\n".to_string();
+        text += &("push dword [".to_string() + &label + "+eax*4]");
+        Command::new(text)
+    }
+
+    fn create_range_guard(&self, left: i64, right: i64, ok_label: String, fail_label: String) -> Command {
+        let mut text = "mov eax, [esp]\n".to_string();
+        text += &("cmp eax, ".to_string() + &left.to_string() + "\n");
+        text += &("jl ".to_string() + &fail_label + "\n");
+        text += &("cmp eax, ".to_string() + &right.to_string() + "\n");
+        text += &("jle ".to_string() + &ok_label);
+        Command::new(text)
+    }
+
+    fn create_call_exit(&self, symbol: String, code: i32) -> Command {
+        let mut text = "push ".to_string() + &code.to_string() + "\n";
+        text += &Command::create_call_func(symbol).as_str();
+        Command::new(text)
+    }
+
+    fn create_call_printf(&self, symbol: String, format: String, doubles: &Vec<bool>) -> Command {
+        let mut text = Command::create_push_global_var(format).as_str() + "\n";
+        text += &(Command::create_call_func(symbol).as_str() + "\n");
+
+        // double занимает на стеке два слова
+        let fcount = doubles.iter().filter(|is_double| **is_double).count();
+        let shift = 4 * ((doubles.len() + fcount + 1) as i32);
+        text += &self.create_clear_stack(shift).as_str();
+        Command::new(text)
+    }
+}
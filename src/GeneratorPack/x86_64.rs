@@ -0,0 +1,413 @@
+use TokenizerPack::*;
+use GeneratorPack::command::*;
+use GeneratorPack::backend::*;
+
+// System V: целые аргументы printf после формата, вещественные - в xmm0..xmm7
+const INT_ARG_REGS: [&'static str; 5] = ["rsi", "rdx", "rcx", "r8", "r9"];
+const FLOAT_ARG_REGS: usize = 8;
+
+pub struct X86_64;
+
+impl Backend for X86_64 {
+    fn word_size(&self) -> i32 {
+        8
+    }
+
+    fn float_size(&self) -> i32 {
+        8
+    }
+
+    fn frame_reg(&self) -> String {
+        "rbp".to_string()
+    }
+
+    fn link_reg(&self) -> String {
+        "rcx".to_string()
+    }
+
+    fn int_format(&self) -> String {
+        "%ld".to_string()
+    }
+
+    fn create_main_entry(&self, symbol: String) -> Command {
+        // по System V main обязана сохранить rbx, а генератор его портит
+        let text = Command::create_decl_function(symbol).as_str() + "\npush rbx";
+        Command::new(text)
+    }
+
+    fn create_main_exit(&self) -> Command {
+        let mut text = "pop rbx\n".to_string();
+        text += "xor eax, eax\n";
+        text += "ret";
+        Command::new(text)
+    }
+
+    fn create_int_var(&self, name: String, value: i64) -> Command {
+        let text = "v_".to_string() + &name + " : dq " + &value.to_string();
+        Command::new(text)
+    }
+
+    fn create_float_var(&self, name: String, value: f64) -> Command {
+        let text = format!("v_{} : dq 0x{:x}", name, value.to_bits());
+        Command::new(text)
+    }
+
+    fn create_push_const(&self, value: i64) -> Command {
+        // push принимает только 32-битное знаковое непосредственное значение
+        if value >= i32::min_value() as i64 && value <= i32::max_value() as i64 {
+            let text = "push ".to_string() + &value.to_string();
+            return Command::new(text);
+        }
+
+        let mut text = "mov rax, ".to_string() + &value.to_string() + "\n";
+        text += "push rax";
+        Command::new(text)
+    }
+
+    fn create_push_float_const(&self, value: f64) -> Command {
+        let mut text = format!("mov rax, 0x{:x}\n", value.to_bits());
+        text += "push rax";
+        Command::new(text)
+    }
+
+    fn create_push_var(&self, addr: String) -> Command {
+        let text = "push qword [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_push_float_var(&self, addr: String) -> Command {
+        self.create_push_var(addr)
+    }
+
+    fn create_push_var_addr(&self, addr: String) -> Command {
+        let mut text = "lea rax, [".to_string() + &addr + "]\n";
+        text += "push rax";
+        Command::new(text)
+    }
+
+    fn create_push_int_result(&self) -> Command {
+        Command::create_push_reg("rax".to_string())
+    }
+
+    fn create_int_op(&self, op: TokenType) -> Command {
+        let mut text = "pop rbx\n".to_string();
+        text += "pop rax\n";
+
+        text += match op {
+            TokenType::TPlus => "add rax, rbx\n",
+            TokenType::TMinus => "sub rax, rbx\n",
+            TokenType::TMul => "imul rax, rbx\n",
+            TokenType::TDiv => "cqo\nidiv rbx\n",
+            TokenType::TMod => "cqo\nidiv rbx\nmov rax, rdx\n",
+            TokenType::TAnd => "and rax, rbx\n",
+            TokenType::TOr => "or rax, rbx\n",
+            _ => "",
+        };
+        text += "push rax";
+
+        Command::new(text)
+    }
+
+    fn create_int_unar_op(&self, op: TokenType) -> Command {
+        let mut text = "pop rax\n".to_string();
+
+        text += match op {
+            TokenType::TPlus => "",
+            TokenType::TMinus => "neg rax\n",
+            TokenType::TNot => "not rax\n",
+            _ => "",
+        };
+        text += "push rax";
+
+        Command::new(text)
+    }
+
+    fn create_int_add_const(&self, value: i64) -> Command {
+        let text = "add qword [rsp], ".to_string() + &value.to_string();
+        Command::new(text)
+    }
+
+    fn create_index_offset(&self, left: i64, stride: i64) -> Command {
+        let mut text = "pop rax\n".to_string();
+        if left != 0 {
+            text += &("sub rax, ".to_string() + &left.to_string() + "\n");
+        }
+        text += &("imul rax, rax, ".to_string() + &stride.to_string() + "\n");
+        text += "add [rsp], rax";
+        Command::new(text)
+    }
+
+    fn create_bool_not(&self) -> Command {
+        Command::new("xor qword [rsp], 1".to_string())
+    }
+
+    fn create_float_op(&self, op: TokenType) -> Command {
+        let mut text = "movsd xmm1, [rsp]\n".to_string();
+        text += "movsd xmm0, [rsp+8]\n";
+        text += "add rsp, 8\n";
+
+        text += match op {
+            TokenType::TPlus => "addsd xmm0, xmm1\n",
+            TokenType::TMinus => "subsd xmm0, xmm1\n",
+            TokenType::TMul => "mulsd xmm0, xmm1\n",
+            TokenType::TShare => "divsd xmm0, xmm1\n",
+            _ => "",
+        };
+        text += "movsd [rsp], xmm0";
+
+        Command::new(text)
+    }
+
+    fn create_float_unar_op(&self, op: TokenType) -> Command {
+        let text = match op {
+            TokenType::TMinus => "btc qword [rsp], 63",
+            _ => "",
+        }.to_string();
+
+        Command::new(text)
+    }
+
+    fn create_assign(&self, addr: String) -> Command {
+        let mut text = "pop rax\n".to_string();
+        text += &("mov [".to_string() + &addr + "], rax");
+        Command::new(text)
+    }
+
+    fn create_float_assign(&self, addr: String) -> Command {
+        self.create_assign(addr)
+    }
+
+    fn create_enter(&self, locals_size: i32) -> Command {
+        let mut text = "push rbp\n".to_string();
+        text += "mov rbp, rsp";
+        if locals_size > 0 {
+            text += &("\nsub rsp, ".to_string() + &locals_size.to_string());
+        }
+        Command::new(text)
+    }
+
+    fn create_leave(&self, shift: i32) -> Command {
+        let mut text = "mov rsp, rbp\n".to_string();
+        text += "pop rbp\n";
+        text += &("ret ".to_string() + &shift.to_string());
+        Command::new(text)
+    }
+
+    fn create_static_link_walk(&self, count: usize) -> Command {
+        let mut text = "mov rcx, rbp".to_string();
+        for _i in 0..count {
+            text += "\nmov rcx, [rcx+16]";
+        }
+        Command::new(text)
+    }
+
+    fn create_load_ref(&self, addr: String) -> Command {
+        let text = "mov rcx, [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_load_int_result(&self, addr: String) -> Command {
+        let text = "mov rax, [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_load_float_result(&self, addr: String) -> Command {
+        let text = "movsd xmm0, [".to_string() + &addr + "]";
+        Command::new(text)
+    }
+
+    fn create_push_float_result(&self) -> Command {
+        let mut text = "sub rsp, 8\n".to_string();
+        text += "movsd [rsp], xmm0";
+        Command::new(text)
+    }
+
+    fn create_int_to_float(&self) -> Command {
+        let mut text = "cvtsi2sd xmm0, qword [rsp]\n".to_string();
+        text += "movsd [rsp], xmm0";
+        Command::new(text)
+    }
+
+    fn create_clear_stack(&self, shift: i32) -> Command {
+        let text = "add rsp, ".to_string() + &shift.to_string();
+        Command::new(text)
+    }
+
+    // Вещественные операнды остаются на стеке, их снимают сами операции
+    fn create_push_to_fld(&self) -> Command {
+        Command::new("".to_string())
+    }
+
+    fn create_push_int_to_fld(&self) -> Command {
+        self.create_int_to_float()
+    }
+
+    fn create_int_cmp(&self, op: TokenType) -> Command {
+        let mut text = "pop rbx\n".to_string();
+        text += "pop rax\n";
+        text += "cmp rax, rbx\n";
+        text += &(int_setcc(op) + " al\n");
+        text += "movzx eax, al\n";
+        text += "push rax";
+        Command::new(text)
+    }
+
+    fn create_float_cmp(&self, op: TokenType) -> Command {
+        let mut text = "movsd xmm1, [rsp]\n".to_string();
+        text += "movsd xmm0, [rsp+8]\n";
+        text += "add rsp, 16\n";
+        text += "ucomisd xmm0, xmm1\n";
+        text += &(float_setcc(op) + " al\n");
+        text += "movzx eax, al\n";
+        text += "push rax";
+        Command::new(text)
+    }
+
+    fn create_jump_if_false(&self, label: String) -> Command {
+        let mut text = "pop rax\n".to_string();
+        text += "cmp rax, 0\n";
+        text += &("je ".to_string() + &label);
+        Command::new(text)
+    }
+
+    fn create_jump_if_true(&self, label: String) -> Command {
+        let mut text = "pop rax\n".to_string();
+        text += "cmp rax, 0\n";
+        text += &("jne ".to_string() + &label);
+        Command::new(text)
+    }
+
+    fn create_int_cmp_jump_if_false(&self, op: TokenType, label: String) -> Command {
+        let mut text = "pop rbx\n".to_string();
+        text += "pop rax\n";
+        text += "cmp rax, rbx\n";
+
+        text += match op {
+            TokenType::TGe => "jl ",
+            TokenType::TGt => "jle ",
+            TokenType::TEq => "jne ",
+            TokenType::TLe => "jg ",
+            TokenType::TLt => "jge ",
+            TokenType::TNe => "je ",
+            _ => "jmp ",
+        };
+        text += &label;
+
+        Command::new(text)
+    }
+
+    fn create_float_cmp_jump_if_false(&self, op: TokenType, label: String) -> Command {
+        let mut text = "movsd xmm1, [rsp]\n".to_string();
+        text += "movsd xmm0, [rsp+8]\n";
+        text += "add rsp, 16\n";
+        text += "ucomisd xmm0, xmm1\n";
+
+        text += match op {
+            TokenType::TGe => "jb ",
+            TokenType::TGt => "jbe ",
+            TokenType::TEq => "jne ",
+            TokenType::TLe => "ja ",
+            TokenType::TLt => "jae ",
+            TokenType::TNe => "je ",
+            _ => "jmp ",
+        };
+        text += &label;
+
+        Command::new(text)
+    }
+
+    fn create_for_init(&self, addr: String, end_label: String) -> Command {
+        let mut text = "pop rbx\n".to_string();
+        text += "pop rax\n";
+        text += &("mov [".to_string() + &addr + "], rax\n");
+        text += "push rbx\n";
+        text += "cmp rax, rbx\n";
+        text += &("jg ".to_string() + &end_label);
+        Command::new(text)
+    }
+
+    fn create_for_step(&self, addr: String, body_label: String, end_label: String) -> Command {
+        let mut text = "mov rax, [".to_string() + &addr + "]\n";
+        text += "cmp rax, [rsp]\n";
+        text += &("jge ".to_string() + &end_label + "\n");
+        text += &("inc qword [".to_string() + &addr + "]\n");
+        text += &("jmp ".to_string() + &body_label);
+        Command::new(text)
+    }
+
+    fn create_bool_names(&self, label: String) -> Command {
+        let mut text = label.clone() + "_false : db \"FALSE\", 0x0\n";
+        text += &(label.clone() + "_true : db \"TRUE\", 0x0\n");
+        text += &(label.clone() + " : dq " + &label + "_false, " + &label + "_true");
+        Command::new(text)
+    }
+
+    fn create_bool_to_str(&self, label: String) -> Command {
+        // с default rel индексная адресация метки невозможна
+        let mut text = "pop rax\n".to_string();
+        text += &("lea rbx, [".to_string() + &label + "]\n");
+        text += "push qword [rbx+rax*8]";
+        Command::new(text)
+    }
+
+    fn create_range_guard(&self, left: i64, right: i64, ok_label: String, fail_label: String) -> Command {
+        let mut text = "mov rax, [rsp]\n".to_string();
+        text += &("cmp rax, ".to_string() + &left.to_string() + "\n");
+        text += &("jl ".to_string() + &fail_label + "\n");
+        text += &("cmp rax, ".to_string() + &right.to_string() + "\n");
+        text += &("jle ".to_string() + &ok_label);
+        Command::new(text)
+    }
+
+    fn create_call_exit(&self, symbol: String, code: i32) -> Command {
+        let mut text = "and rsp, -16\n".to_string();
+        text += &("mov edi, ".to_string() + &code.to_string() + "\n");
+        text += &("call ".to_string() + &symbol + " wrt ..plt");
+        Command::new(text)
+    }
+
+    fn create_call_printf(&self, symbol: String, format: String, doubles: &Vec<bool>) -> Command {
+        let mut int_args = vec![];
+        let mut float_args = vec![];
+        let mut stack_args = vec![];
+        for (i, is_double) in doubles.iter().enumerate() {
+            let offset = 8 * i;
+            if *is_double && float_args.len() < FLOAT_ARG_REGS {
+                float_args.push(offset);
+            } else if !*is_double && int_args.len() < INT_ARG_REGS.len() {
+                int_args.push(offset);
+            } else {
+                stack_args.push(offset);
+            }
+        }
+
+        // rbx переживает вызов и хранит вершину стека с аргументами;
+        // перед call стек выравнивается на 16
+        let mut text = "mov rbx, rsp\n".to_string();
+        if !stack_args.is_empty() {
+            text += &format!("sub rsp, {}\n", 8 * stack_args.len());
+        }
+        text += "and rsp, -16\n";
+
+        for (i, offset) in stack_args.iter().enumerate() {
+            text += &format!("mov rax, [rbx+{}]\n", offset);
+            text += &format!("mov [rsp+{}], rax\n", 8 * i);
+        }
+        for (reg, offset) in INT_ARG_REGS.iter().zip(int_args.iter()) {
+            text += &format!("mov {}, [rbx+{}]\n", reg, offset);
+        }
+        for (i, offset) in float_args.iter().enumerate() {
+            text += &format!("movsd xmm{}, [rbx+{}]\n", i, offset);
+        }
+
+        text += &("lea rdi, [".to_string() + &format + "]\n");
+        text += &format!("mov eax, {}\n", float_args.len());
+        text += &("call ".to_string() + &symbol + " wrt ..plt\n");
+        text += "mov rsp, rbx";
+        if !doubles.is_empty() {
+            text += &format!("\nadd rsp, {}", 8 * doubles.len());
+        }
+
+        Command::new(text)
+    }
+}
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 use support::*;
@@ -11,20 +12,22 @@ pub struct ArrayElementNode {
     pub index: Rc<Node>,
     pub parent: Rc<Node>,
     pub self_type: Rc<Type>,
+    pub coords: Point,
 }
 
 impl ArrayElementNode {
-    pub fn new(parent: Rc<Node>, index: Rc<Node>) -> Result<ArrayElementNode, SemanticErrors> {
+    pub fn new(parent: Rc<Node>, index: Rc<Node>, coords: Point) -> Result<ArrayElementNode, SemanticErrors> {
         let self_type = try!(
             parent
                 .get_type()
                 .unwrap()
-                .get_by_index(index.get_type().unwrap())
+                .get_by_index(index.get_type().unwrap(), &index.get_const())
         );
         Ok(ArrayElementNode {
             parent,
             index,
             self_type,
+            coords,
         })
     }
 }
@@ -41,7 +44,7 @@ impl PrintableNode for ArrayElementNode {
         vec![self.index.as_printable(), self.parent.as_printable()]
     }
     fn get_caption(&self) -> String {
-        self.get_name() + " : " + &self.self_type.as_str() + " = " + &self.self_type.value_as_str()
+        self.get_name() + " : " + &self.self_type.as_str() + " = " + &self.self_type.value_as_str(&self.get_const())
     }
 }
 
@@ -50,7 +53,7 @@ impl Node for ArrayElementNode {
         Some(self.self_type.clone())
     }
     fn get_name(&self) -> String {
-        self.parent.get_name() + "[" + &self.index.get_type().unwrap().value_as_str() + "]"
+        self.parent.get_name() + "[" + &self.index.get_type().unwrap().value_as_str(&self.index.get_const()) + "]"
     }
     fn get_kind(&self) -> KindIdentifier {
         KindIdentifier::Other
@@ -58,4 +61,39 @@ impl Node for ArrayElementNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.parent = fold_node(&self.parent);
+        e.index = fold_node(&self.index);
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        self.generate_address(generator);
+        generator.emit(Instr::Load(ir_type(self.get_type()), Place::Stack));
+    }
+
+    fn is_addressable(&self) -> bool {
+        self.parent.is_addressable()
+    }
+
+    fn generate_address(&self, generator: &mut Generator) {
+        self.parent.generate_address(generator);
+        self.index.generate(generator);
+
+        let index_type = self.parent.get_type().unwrap().get_index_type().unwrap();
+        let left = index_type.get_left();
+        let right = index_type.get_right();
+        generator.check_bounds(left as i64, right as i64, self.coords.y);
+
+        let stride = storage_size(generator, self.self_type.clone());
+        generator.emit(Instr::IndexOffset {
+            left: left as i64,
+            stride: stride as i64,
+        });
+    }
 }
@@ -22,57 +22,32 @@ impl AssignNode {
         target: Rc<Node>,
         object: Rc<Node>,
     ) -> Result<AssignNode, SemanticErrors> {
-        match op.token_type {
-            TokenType::TAssign => {
-                try!(
-                    target
-                        .get_type()
-                        .unwrap()
-                        .set_value(object.get_type().unwrap())
-                );
-            }
-            TokenType::TPlsAssign => {
-                let new_object = try!(
-                    target
-                        .get_type()
-                        .unwrap()
-                        .bin_operation(object.get_type().unwrap(), BinOperation::Plus)
-                );
-                try!(target.get_type().unwrap().set_value(new_object));
-            }
-            TokenType::TMinAssign => {
-                let new_object = try!(
-                    target
-                        .get_type()
-                        .unwrap()
-                        .bin_operation(object.get_type().unwrap(), BinOperation::Minus)
-                );
-                try!(target.get_type().unwrap().set_value(new_object));
-            }
-            TokenType::TMulAssign => {
-                let new_object = try!(
-                    target
-                        .get_type()
-                        .unwrap()
-                        .bin_operation(object.get_type().unwrap(), BinOperation::Mul)
-                );
-                try!(target.get_type().unwrap().set_value(new_object));
-            }
-            TokenType::TShareAssign => {
-                let new_object = try!(
-                    target
-                        .get_type()
-                        .unwrap()
-                        .bin_operation(object.get_type().unwrap(), BinOperation::Share)
-                );
-                try!(target.get_type().unwrap().set_value(new_object));
+        if let KindIdentifier::Other = target.get_kind() {
+            if !target.is_addressable() {
+                return Err(SemanticErrors::ExpectedVariable);
             }
+        }
+
+        let target_type = target.get_type().unwrap();
+        let value = match op.token_type {
+            TokenType::TAssign => object.get_type().unwrap(),
+            TokenType::TPlsAssign => try!(
+                target_type.bin_operation(object.get_type().unwrap(), BinOperation::Plus)
+            ),
+            TokenType::TMinAssign => try!(
+                target_type.bin_operation(object.get_type().unwrap(), BinOperation::Minus)
+            ),
+            TokenType::TMulAssign => try!(
+                target_type.bin_operation(object.get_type().unwrap(), BinOperation::Mul)
+            ),
+            TokenType::TShareAssign => try!(
+                target_type.bin_operation(object.get_type().unwrap(), BinOperation::Share)
+            ),
             _ => {
-                return Err(SemanticErrors::OtherError {
-                    msg: "Ожидалось += -= := *= /=".to_string(),
-                })
+                return Err(SemanticErrors::ExpectedAssignOperator)
             }
         };
+        try!(target_type.check_assign(value));
 
         Ok(AssignNode { op, target, object })
     }
@@ -104,11 +79,36 @@ impl Node for AssignNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.op.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.target = fold_node(&self.target);
+        e.object = fold_node(&self.object);
+        Some(Rc::new(e))
+    }
 
     fn generate(&self, generator: &mut Generator) {
         self.object.generate(generator);
 
-        let command = Command::create_assing(self.target.get_name());
-        generator.push_to_text_section(command);
+        if let Some((left, right)) = self.target.get_type().unwrap().as_range() {
+            generator.check_range(left, right, self.op.coords.y);
+        }
+
+        let target_is_double = is_double_type(self.target.get_type());
+        if target_is_double && !is_double_type(self.object.get_type()) {
+            generator.emit(Instr::IntToDouble);
+        }
+
+        let place = match self.target.get_kind() {
+            KindIdentifier::Other => {
+                self.target.generate_address(generator);
+                Place::Stack
+            }
+            _ => generator.var_address(self.target.get_name()),
+        };
+        generator.emit(Instr::Store(ir_type(self.target.get_type()), place));
     }
 }
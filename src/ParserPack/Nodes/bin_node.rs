@@ -15,6 +15,7 @@ pub struct BinNode {
     pub right: Rc<Node>,
 
     pub self_type: Rc<Type>,
+    pub value: ConstValue,
     pub op: Token,
 }
 
@@ -25,6 +26,8 @@ impl BinNode {
             TokenType::TMinus => BinOperation::Minus,
             TokenType::TMul => BinOperation::Mul,
             TokenType::TShare => BinOperation::Share,
+            TokenType::TDiv => BinOperation::Div,
+            TokenType::TMod => BinOperation::Mod,
             TokenType::TAnd => BinOperation::And,
             TokenType::TOr => BinOperation::Or,
             TokenType::TGe => BinOperation::OGe,
@@ -33,11 +36,7 @@ impl BinNode {
             TokenType::TLe => BinOperation::OLe,
             TokenType::TLt => BinOperation::OLt,
             TokenType::TNe => BinOperation::ONe,
-            _ => return Err(SemanticErrors::OtherError {
-                msg:
-                    "Ожидалось * / + - or and или операторы сравнения"
-                        .to_string(),
-            }),
+            _ => return Err(SemanticErrors::ExpectedBinOperator),
         };
 
         let self_type = try!(
@@ -45,11 +44,13 @@ impl BinNode {
                 .unwrap()
                 .bin_operation(right.get_type().unwrap(), op_type)
         );
+        let value = ConstValue::fold_bin(op_type, &left.get_const(), &right.get_const());
         Ok(BinNode {
             op,
             left,
             right,
             self_type,
+            value,
         })
     }
 }
@@ -67,7 +68,7 @@ impl PrintableNode for BinNode {
     }
     fn get_caption(&self) -> String {
         self.op.text.to_string() + " : " + &self.self_type.as_str() + " = "
-            + &self.self_type.value_as_str()
+            + &self.self_type.value_as_str(&self.value)
     }
 }
 
@@ -75,6 +76,9 @@ impl Node for BinNode {
     fn get_type(&self) -> Option<Rc<Type>> {
         Some(self.self_type.clone())
     }
+    fn get_const(&self) -> ConstValue {
+        self.value.clone()
+    }
     fn get_name(&self) -> String {
         self.op.text.to_string()
     }
@@ -84,27 +88,142 @@ impl Node for BinNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.op.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let folded = fold_to_const(self.self_type.clone(), &self.value, self.op.coords.clone());
+        if folded.is_some() {
+            return folded;
+        }
+        let mut e = self.clone();
+        e.left = fold_node(&self.left);
+        e.right = fold_node(&self.right);
+        Some(Rc::new(e))
+    }
 
     fn generate(&self, generator: &mut Generator) {
-        match self.self_type.get_value() {
-            ValueVariant::Int { v } => {
+        if is_relation(&self.op.token_type) {
+            let op_type = self.generate_operands(generator);
+            generator.emit(Instr::Compare(op_type, self.op.clone().token_type));
+            return;
+        }
+
+        match self.self_type.get_variant() {
+            ValueVariant::Int => {
                 self.left.generate(generator);
                 self.right.generate(generator);
-                let command = Command::create_int_op(self.op.clone().token_type);
-                generator.push_to_text_section(command);
+                generator.emit(Instr::Binary(IrType::Int, self.op.clone().token_type));
+                match self.op.token_type {
+                    TokenType::TPlus | TokenType::TMinus | TokenType::TMul => {
+                        generator.check_overflow(self.op.coords.y);
+                    }
+                    _ => {}
+                }
             }
-            ValueVariant::Double { v } => {
-                self.left.generate(generator);
-                generator.push_to_text_section(Command::create_push_to_fld());
+            ValueVariant::Double => {
+                // у / оба операнда могут быть целыми, деление все равно вещественное
+                self.generate_float_operands(generator);
+                generator.emit(Instr::Binary(IrType::Double, self.op.clone().token_type));
+            }
+            ValueVariant::Enum { .. } => {
+                if generator.short_circuit {
+                    self.generate_short_circuit(generator);
+                    return;
+                }
 
+                self.left.generate(generator);
                 self.right.generate(generator);
-                generator.push_to_text_section(Command::create_push_to_fld());
-
-                let command = Command::create_float_op(self.op.clone().token_type);
-                generator.push_to_text_section(command);
+                generator.emit(Instr::Binary(IrType::Int, self.op.clone().token_type));
             }
-            ValueVariant::Enum { name, v } => {}
             _ => {}
         }
     }
+
+    fn generate_jump_if_false(&self, generator: &mut Generator, label: String) {
+        if generator.short_circuit && self.op.token_type == TokenType::TAnd {
+            self.left.generate_jump_if_false(generator, label.clone());
+            self.right.generate_jump_if_false(generator, label);
+            return;
+        }
+
+        if !is_relation(&self.op.token_type) {
+            self.generate(generator);
+            generator.emit(Instr::JumpIfFalse(label));
+            return;
+        }
+
+        let op_type = self.generate_operands(generator);
+        generator.emit(Instr::CompareJumpIfFalse(op_type, self.op.clone().token_type, label));
+    }
+}
+
+impl BinNode {
+    // Кладет операнды на стек; если хотя бы один вещественный, оба готовятся
+    // для вещественной операции; возвращает тип, в котором выполняется операция
+    fn generate_operands(&self, generator: &mut Generator) -> IrType {
+        let left_is_double = is_double_type(self.left.get_type());
+        let right_is_double = is_double_type(self.right.get_type());
+
+        if !left_is_double && !right_is_double {
+            self.left.generate(generator);
+            self.right.generate(generator);
+            return IrType::Int;
+        }
+
+        self.generate_float_operands(generator);
+        IrType::Double
+    }
+
+    // Оба операнда сначала вычисляются на обычном стеке и только потом переносятся в FPU:
+    // иначе вызов функции в правом операнде оставлял бы левый в стеке x87 на каждом уровне рекурсии.
+    // Переносится сначала правый, так что в FPU левый оказывается на вершине
+    fn generate_float_operands(&self, generator: &mut Generator) {
+        self.left.generate(generator);
+        if !is_double_type(self.left.get_type()) {
+            generator.emit(Instr::IntToDouble);
+        }
+
+        self.right.generate(generator);
+        if !is_double_type(self.right.get_type()) {
+            generator.emit(Instr::IntToDouble);
+        }
+
+        generator.emit(Instr::ToFloatUnit(IrType::Double));
+        generator.emit(Instr::ToFloatUnit(IrType::Double));
+    }
+
+    // and/or над boolean: правый операнд вычисляется, только если от него зависит результат
+    fn generate_short_circuit(&self, generator: &mut Generator) {
+        let skip_label = generator.next_label();
+        let end_label = generator.next_label();
+
+        let skip_value = if self.op.token_type == TokenType::TAnd {
+            self.left.generate_jump_if_false(generator, skip_label.clone());
+            0
+        } else {
+            self.left.generate(generator);
+            generator.emit(Instr::JumpIfTrue(skip_label.clone()));
+            1
+        };
+
+        self.right.generate(generator);
+        generator.emit(Instr::Jump(end_label.clone()));
+        generator.emit_label(skip_label);
+        generator.emit(Instr::Const(skip_value));
+        generator.emit_label(end_label);
+    }
+}
+
+fn is_relation(op: &TokenType) -> bool {
+    match *op {
+        TokenType::TGe
+        | TokenType::TGt
+        | TokenType::TEq
+        | TokenType::TLe
+        | TokenType::TLt
+        | TokenType::TNe => true,
+        _ => false,
+    }
 }
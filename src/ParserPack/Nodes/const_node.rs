@@ -3,17 +3,36 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 
 #[derive(Clone)]
 pub struct ConstNode {
     pub self_type: Rc<Type>,
+    pub value: ConstValue,
+    pub is_folded: bool,
+    pub coords: Point,
 }
 
 impl ConstNode {
-    pub fn new(self_type: Rc<Type>) -> ConstNode {
-        ConstNode { self_type }
+    pub fn new(self_type: Rc<Type>, value: ConstValue, coords: Point) -> ConstNode {
+        ConstNode {
+            self_type,
+            value,
+            is_folded: false,
+            coords,
+        }
+    }
+
+    // Литерал, полученный сверткой выражения
+    pub fn folded(self_type: Rc<Type>, value: ConstValue, coords: Point) -> ConstNode {
+        ConstNode {
+            self_type,
+            value,
+            is_folded: true,
+            coords,
+        }
     }
 }
 
@@ -29,7 +48,11 @@ impl PrintableNode for ConstNode {
         vec![]
     }
     fn get_caption(&self) -> String {
-        self.self_type.value_as_str() + ": " + &self.self_type.as_str()
+        let caption = self.self_type.value_as_str(&self.value) + ": " + &self.self_type.as_str();
+        if self.is_folded {
+            return caption + " (folded)";
+        }
+        caption
     }
 }
 
@@ -37,8 +60,11 @@ impl Node for ConstNode {
     fn get_type(&self) -> Option<Rc<Type>> {
         Some(self.self_type.clone())
     }
+    fn get_const(&self) -> ConstValue {
+        self.value.clone()
+    }
     fn get_name(&self) -> String {
-        self.self_type.value_as_str()
+        self.self_type.value_as_str(&self.value)
     }
     fn get_kind(&self) -> KindIdentifier {
         KindIdentifier::Const
@@ -46,23 +72,16 @@ impl Node for ConstNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
 
     fn generate(&self, generator: &mut Generator) {
-        match self.self_type.get_value() {
-            ValueVariant::Int { v } => {
-                let command = Command::create_push_const(v as i32);
-                generator.push_to_text_section(command);
-            }
-            ValueVariant::Double { v } => {
-                let command = Command::create_push_const((v as f32).to_bits() as i32);
-                generator.push_to_text_section(command);
-            }
-            ValueVariant::Char { v } => {
-                let command = Command::create_push_const(v as i32);
-                generator.push_to_text_section(command);
-            }
-            ValueVariant::Enum { name, v } => {}
-            ValueVariant::Other => {}
-        }
+        let instr = match self.self_type.get_variant() {
+            ValueVariant::Double => Instr::ConstDouble(self.value.as_double().unwrap()),
+            ValueVariant::Other => return,
+            _ => Instr::Const(self.self_type.as_ordinal(&self.value).unwrap()),
+        };
+        generator.emit(instr);
     }
 }
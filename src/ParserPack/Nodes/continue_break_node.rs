@@ -2,17 +2,19 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 
 #[derive(Clone)]
 pub struct ContinueBreakNode {
     pub name: String,
+    pub coords: Point,
 }
 
 impl ContinueBreakNode {
-    pub fn new(name: String) -> ContinueBreakNode {
-        ContinueBreakNode { name }
+    pub fn new(name: String, coords: Point) -> ContinueBreakNode {
+        ContinueBreakNode { name, coords }
     }
 }
 
@@ -45,4 +47,16 @@ impl Node for ContinueBreakNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let label = if self.name == "Break" {
+            generator.break_label()
+        } else {
+            generator.continue_label()
+        };
+        generator.emit(Instr::Jump(label));
+    }
 }
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 
@@ -9,11 +10,23 @@ use ParserPack::*;
 pub struct DeclConstVarNode {
     pub name: String,
     pub self_type: Rc<Type>,
+    pub value: ConstValue,
+    pub coords: Point,
 }
 
 impl DeclConstVarNode {
-    pub fn new(name: String, self_type: Rc<Type>) -> DeclConstVarNode {
-        DeclConstVarNode { name, self_type }
+    pub fn new(
+        name: String,
+        self_type: Rc<Type>,
+        value: ConstValue,
+        coords: Point,
+    ) -> DeclConstVarNode {
+        DeclConstVarNode {
+            name,
+            self_type,
+            value,
+            coords,
+        }
     }
 }
 
@@ -29,7 +42,8 @@ impl PrintableNode for DeclConstVarNode {
         vec![]
     }
     fn get_caption(&self) -> String {
-        self.name.to_string() + "(" + &self.self_type.as_str() + ")"
+        self.name.to_string() + "(" + &self.self_type.as_str() + ") = "
+            + &self.self_type.value_as_str(&self.value)
     }
 }
 
@@ -37,6 +51,9 @@ impl Node for DeclConstVarNode {
     fn get_type(&self) -> Option<Rc<Type>> {
         Some(self.self_type.clone())
     }
+    fn get_const(&self) -> ConstValue {
+        self.value.clone()
+    }
     fn get_name(&self) -> String {
         self.name.clone()
     }
@@ -47,4 +64,7 @@ impl Node for DeclConstVarNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
 }
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 
 #[derive(Clone)]
@@ -9,11 +10,16 @@ pub struct DeclVarListNode {
     pub name: String,
 
     pub fields: Vec<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl DeclVarListNode {
-    pub fn new(name: String, fields: Vec<Rc<Node>>) -> DeclVarListNode {
-        DeclVarListNode { name, fields }
+    pub fn new(name: String, fields: Vec<Rc<Node>>, coords: Point) -> DeclVarListNode {
+        DeclVarListNode {
+            name,
+            fields,
+            coords,
+        }
     }
 }
 
@@ -41,6 +47,9 @@ impl Node for DeclVarListNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
 
     fn generate(&self, generator: &mut Generator) {
         for child in &self.fields {
@@ -3,6 +3,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 
@@ -10,11 +11,18 @@ use ParserPack::*;
 pub struct DeclVarNode {
     pub name: String,
     pub self_type: Rc<Type>,
+    pub value: ConstValue,
+    pub coords: Point,
 }
 
 impl DeclVarNode {
-    pub fn new(name: String, self_type: Rc<Type>) -> DeclVarNode {
-        DeclVarNode { name, self_type }
+    pub fn new(name: String, self_type: Rc<Type>, value: ConstValue, coords: Point) -> DeclVarNode {
+        DeclVarNode {
+            name,
+            self_type,
+            value,
+            coords,
+        }
     }
 }
 
@@ -31,7 +39,7 @@ impl PrintableNode for DeclVarNode {
     }
     fn get_caption(&self) -> String {
         self.name.to_string() + " : " + &self.self_type.as_str() + " = "
-            + &self.self_type.value_as_str()
+            + &self.self_type.value_as_str(&self.value)
     }
 }
 
@@ -49,23 +57,65 @@ impl Node for DeclVarNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
 
     fn generate(&self, generator: &mut Generator) {
-        match self.self_type.get_value() {
-            ValueVariant::Int { v } => {
-                let command = Command::create_int_var(self.name.clone());
-                generator.push_to_data_section(command);
-            }
-            ValueVariant::Double { v } => {
-                let command = Command::create_float_var(self.name.clone());
-                generator.push_to_data_section(command);
-            }
-            ValueVariant::Char { v } => {
-                let command = Command::create_int_var(self.name.clone());
-                generator.push_to_data_section(command);
+        let size = storage_size(generator, self.self_type.clone());
+        if let Some(offset) = generator.add_local(self.name.clone(), size) {
+            // локальную переменную с начальным значением заполняем при входе
+            if let ConstValue::Aggregate(ref items) = self.value {
+                let mut item_offset = offset;
+                for item in items {
+                    let value_type = match ir_value(item) {
+                        IrValue::Int(v) => {
+                            generator.emit(Instr::Const(v));
+                            IrType::Int
+                        }
+                        IrValue::Double(v) => {
+                            generator.emit(Instr::ConstDouble(v));
+                            IrType::Double
+                        }
+                    };
+                    let place = Place::Frame { up: 0, offset: item_offset };
+                    generator.emit(Instr::Store(value_type, place));
+                    item_offset += generator.value_size(value_type == IrType::Double);
+                }
+            } else if self.value.is_known() {
+                ConstNode::new(self.self_type.clone(), self.value.clone(), self.coords.clone())
+                    .generate(generator);
+                let place = generator.var_address(self.name.clone());
+                generator.emit(Instr::Store(ir_type(self.get_type()), place));
             }
-            ValueVariant::Enum { name, v } => {}
-            ValueVariant::Other => {}
+            return;
         }
+
+        let name = self.name.clone();
+        let data = match self.self_type.get_variant() {
+            ValueVariant::Double => IrData::Double {
+                name,
+                value: self.value.as_double().unwrap_or(0.0),
+            },
+            ValueVariant::Other => match self.value {
+                ConstValue::Aggregate(ref items) => IrData::Words {
+                    name,
+                    items: items.iter().map(|item| ir_value(item)).collect(),
+                },
+                _ => IrData::Bytes { name, size },
+            },
+            _ => IrData::Int {
+                name,
+                value: self.self_type.as_ordinal(&self.value).unwrap_or(0),
+            },
+        };
+        generator.emit_data(data);
+    }
+}
+
+fn ir_value(value: &ConstValue) -> IrValue {
+    match *value {
+        ConstValue::Double(v) => IrValue::Double(v),
+        _ => IrValue::Int(value.as_index().unwrap_or(0)),
     }
 }
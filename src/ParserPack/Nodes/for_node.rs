@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use support::*;
 
@@ -13,6 +14,7 @@ pub struct ForNode {
     pub finish: Rc<Node>,
 
     pub block: Rc<Node>,
+    pub coords: Point,
 }
 
 impl ForNode {
@@ -21,32 +23,25 @@ impl ForNode {
         start: Rc<Node>,
         finish: Rc<Node>,
         block: Rc<Node>,
+        coords: Point,
     ) -> Result<ForNode, SemanticErrors> {
         let id_type = id.get_type().unwrap();
         let start_type = start.get_type().unwrap();
         let finish_type = finish.get_type().unwrap();
 
         if !id_type.is_enumerated() {
-            return Err(SemanticErrors::OtherError {
-                msg: "Ожидался перечислимый тип".to_string(),
-            });
+            return Err(SemanticErrors::ExpectedEnumerated);
         }
         if id_type.as_str() != start_type.as_str() {
-            return Err(SemanticErrors::OtherError {
-                msg: format!(
-                    "Невозможно привести {} к {}",
-                    start_type.as_str(),
-                    id_type.as_str()
-                ),
+            return Err(SemanticErrors::InvalidConversion {
+                from: start_type.as_str(),
+                to: id_type.as_str(),
             });
         }
         if id_type.as_str() != finish_type.as_str() {
-            return Err(SemanticErrors::OtherError {
-                msg: format!(
-                    "Невозможно привести {} к {}",
-                    finish_type.as_str(),
-                    id_type.as_str()
-                ),
+            return Err(SemanticErrors::InvalidConversion {
+                from: finish_type.as_str(),
+                to: id_type.as_str(),
             });
         }
 
@@ -55,6 +50,7 @@ impl ForNode {
             start,
             finish,
             block,
+            coords,
         })
     }
 }
@@ -90,4 +86,46 @@ impl Node for ForNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.start = fold_node(&self.start);
+        e.finish = fold_node(&self.finish);
+        e.block = fold_node(&self.block);
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let body_label = generator.next_label();
+        let continue_label = generator.next_label();
+        let break_label = generator.next_label();
+
+        self.start.generate(generator);
+        self.finish.generate(generator);
+
+        let place = generator.var_address(self.id.get_name());
+        generator.emit(Instr::ForInit {
+            place,
+            end: break_label.clone(),
+        });
+        generator.emit_label(body_label.clone());
+
+        generator.push_loop(continue_label.clone(), break_label.clone());
+        self.block.generate(generator);
+        generator.pop_loop();
+
+        generator.emit_label(continue_label);
+        let place = generator.var_address(self.id.get_name());
+        generator.emit(Instr::ForStep {
+            place,
+            body: body_label,
+            end: break_label.clone(),
+        });
+
+        generator.emit_label(break_label);
+        generator.emit(Instr::Drop(IrType::Int));
+    }
 }
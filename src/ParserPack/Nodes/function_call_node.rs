@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 use support::*;
@@ -11,15 +12,24 @@ pub struct FunctionCallNode {
     pub func_name: String,
     pub parent: Rc<Type>,
     pub self_type: Rc<Type>,
+    pub args: Vec<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl FunctionCallNode {
-    pub fn new(func_name: String, parent: Rc<Type>) -> Result<FunctionCallNode, SemanticErrors> {
+    pub fn new(
+        func_name: String,
+        parent: Rc<Type>,
+        args: Vec<Rc<Node>>,
+        coords: Point,
+    ) -> Result<FunctionCallNode, SemanticErrors> {
         let self_type = try!(parent.call_by_args());
         Ok(FunctionCallNode {
             func_name,
             parent,
             self_type,
+            args,
+            coords,
         })
     }
 }
@@ -33,10 +43,14 @@ impl Display for FunctionCallNode {
 
 impl PrintableNode for FunctionCallNode {
     fn get_children(&self) -> Vec<&PrintableNode> {
-        vec![]
+        let mut ans: Vec<&PrintableNode> = vec![];
+        for child in &self.args {
+            ans.push(child.as_printable())
+        }
+        ans
     }
     fn get_caption(&self) -> String {
-        self.get_name() + ": " + &self.self_type.as_str() + " = " + &self.self_type.value_as_str()
+        self.get_name() + ": " + &self.self_type.as_str() + " = " + &self.self_type.value_as_str(&self.get_const())
     }
 }
 
@@ -45,7 +59,16 @@ impl Node for FunctionCallNode {
         Some(self.self_type.clone())
     }
     fn get_name(&self) -> String {
-        self.func_name.clone() + &self.parent.value_as_str()
+        let args: Vec<String> = self.args
+            .iter()
+            .map(|arg| arg.get_type().unwrap().value_as_str(&arg.get_const()))
+            .collect();
+
+        if args.len() == 0 {
+            self.func_name.clone() + "(void)"
+        } else {
+            self.func_name.clone() + "(" + &args.join(", ") + ")"
+        }
     }
     fn get_kind(&self) -> KindIdentifier {
         KindIdentifier::Other
@@ -53,4 +76,50 @@ impl Node for FunctionCallNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.args = fold_nodes(&self.args);
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let func_type = self.parent.clone();
+        let params = match func_type.get_args() {
+            Some(res) => res,
+            None => vec![],
+        };
+
+        for (arg, param) in self.args.iter().zip(params.iter()) {
+            match param.get_kind() {
+                TypeKind::VarArg => arg.generate_address(generator),
+                _ => {
+                    arg.generate(generator);
+                    match (param.get_variant(), arg.get_type().unwrap().get_variant()) {
+                        (ValueVariant::Double, ValueVariant::Int) => {
+                            generator.emit(Instr::IntToDouble);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let (label, depth) = generator.function_label(self.func_name.clone(), func_type.as_str());
+        generator.push_static_link(depth);
+        generator.emit(Instr::Call(label));
+
+        match self.self_type.get_variant() {
+            ValueVariant::Double => {
+                generator.emit(Instr::PushResult(IrType::Double));
+            }
+            ValueVariant::Other => {}
+            _ => {
+                generator.emit(Instr::PushResult(IrType::Int));
+            }
+        }
+    }
 }
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 
@@ -9,7 +10,9 @@ use ParserPack::*;
 pub struct DeclFunctionNode {
     pub name: String,
     pub self_type: Rc<FunctionType>,
+    pub args: Vec<Rc<Node>>,
     pub block: Option<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl DeclFunctionNode {
@@ -18,6 +21,7 @@ impl DeclFunctionNode {
         arg_list: Vec<Rc<Node>>,
         out_type: Rc<Type>,
         block: Option<Rc<Node>>,
+        coords: Point,
     ) -> DeclFunctionNode {
         let arg_list_type = arg_list
             .clone()
@@ -29,7 +33,9 @@ impl DeclFunctionNode {
         DeclFunctionNode {
             name,
             self_type,
+            args: arg_list,
             block,
+            coords,
         }
     }
 }
@@ -71,4 +77,56 @@ impl Node for DeclFunctionNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.block = self.block.as_ref().map(|block| fold_node(block));
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let (label, _depth) = generator.declare_function(self.name.clone(), self.self_type.as_str());
+        let block = match self.block {
+            Some(ref block) => block,
+            None => return,
+        };
+
+        generator.begin_function(label);
+        for arg in self.args.iter().rev() {
+            let by_ref = match arg.get_type().unwrap().get_kind() {
+                TypeKind::VarArg => true,
+                _ => false,
+            };
+            let size = if by_ref {
+                generator.backend.word_size()
+            } else {
+                generator.value_size(is_double_type(arg.get_type()))
+            };
+            generator.add_arg(arg.get_name(), size, by_ref);
+        }
+
+        let out_type = self.self_type.out_type.clone();
+        let result = match out_type.get_variant() {
+            ValueVariant::Other => None,
+            _ => {
+                let size = generator.value_size(is_double_type(Some(out_type.clone())));
+                generator.add_local(self.name.clone(), size)
+            }
+        };
+
+        block.generate(generator);
+
+        match result {
+            Some(offset) => {
+                let place = Place::Frame { up: 0, offset };
+                generator.emit(Instr::LoadResult(ir_type(Some(out_type.clone())), place));
+            }
+            None => {}
+        }
+
+        generator.end_function();
+    }
 }
@@ -2,16 +2,18 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::*;
 
 #[derive(Clone)]
 pub struct IdNode {
     pub child: Rc<Node>,
+    pub coords: Point,
 }
 
 impl IdNode {
-    pub fn new(child: Rc<Node>) -> IdNode {
-        IdNode { child }
+    pub fn new(child: Rc<Node>, coords: Point) -> IdNode {
+        IdNode { child, coords }
     }
 }
 
@@ -27,7 +29,15 @@ impl PrintableNode for IdNode {
         vec![]
     }
     fn get_caption(&self) -> String {
-        self.child.get_caption().to_string()
+        // значение переменной в точке использования неизвестно, в отличие от начального
+        match self.get_kind() {
+            KindIdentifier::Var => {
+                let self_type = self.get_type().unwrap();
+                self.get_name() + " : " + &self_type.as_str() + " = "
+                    + &self_type.value_as_str(&self.get_const())
+            }
+            _ => self.child.get_caption().to_string(),
+        }
     }
 }
 
@@ -35,6 +45,9 @@ impl Node for IdNode {
     fn get_type(&self) -> Option<Rc<Type>> {
         self.child.get_type().clone()
     }
+    fn get_const(&self) -> ConstValue {
+        self.child.get_const()
+    }
     fn get_name(&self) -> String {
         self.child.get_name().clone()
     }
@@ -44,9 +57,45 @@ impl Node for IdNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    // константы подставляются по месту использования
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        match self.get_kind() {
+            KindIdentifier::Const => {
+                fold_to_const(self.get_type().unwrap(), &self.get_const(), self.coords.clone())
+            }
+            _ => None,
+        }
+    }
 
     fn generate(&self, generator: &mut Generator) {
-        let command = Command::create_push_global_var_by_addr(self.child.get_name());
-        generator.push_to_text_section(command);
+        // константы, в том числе true, false и элементы перечислений, не имеют памяти
+        if let KindIdentifier::Const = self.get_kind() {
+            let value = ConstNode::new(
+                self.get_type().unwrap(),
+                self.get_const(),
+                self.coords.clone(),
+            );
+            value.generate(generator);
+            return;
+        }
+
+        let place = generator.var_address(self.child.get_name());
+        generator.emit(Instr::Load(ir_type(self.get_type()), place));
+    }
+
+    fn is_addressable(&self) -> bool {
+        match self.get_kind() {
+            KindIdentifier::Var => true,
+            _ => false,
+        }
+    }
+
+    fn generate_address(&self, generator: &mut Generator) {
+        let place = generator.var_address(self.child.get_name());
+        generator.emit(Instr::LoadAddr(place));
     }
 }
@@ -2,7 +2,9 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
+use ParserPack::ProgramNode;
 use support::*;
 
 #[derive(Clone)]
@@ -11,6 +13,7 @@ pub struct IfNode {
 
     pub block: Rc<Node>,
     pub else_block: Option<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl IfNode {
@@ -18,21 +21,17 @@ impl IfNode {
         cond: Rc<Node>,
         block: Rc<Node>,
         else_block: Option<Rc<Node>>,
+        coords: Point,
     ) -> Result<IfNode, SemanticErrors> {
-        if !cond.get_type()
-            .unwrap()
-            .as_enum("boolean".to_string())
-            .is_none()
-        {
+        if is_boolean_type(cond.get_type()) {
             Ok(IfNode {
                 cond,
                 block,
                 else_block,
+                coords,
             })
         } else {
-            Err(SemanticErrors::OtherError {
-                msg: "Ожидалось логическое выражение".to_string(),
-            })
+            Err(SemanticErrors::ExpectedBoolean)
         }
     }
 }
@@ -70,4 +69,52 @@ impl Node for IfNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    // ветвь, которая никогда не выполнится, удаляется вместе с проверкой
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let cond = fold_node(&self.cond);
+        let block = fold_node(&self.block);
+        let else_block = self.else_block.as_ref().map(|block| fold_node(block));
+
+        match cond.get_const().as_boolean() {
+            Some(true) => Some(block),
+            Some(false) => match else_block {
+                Some(res) => Some(res),
+                None => Some(Rc::new(ProgramNode::new(
+                    "Statements".to_string(),
+                    self.coords.clone(),
+                )) as Rc<Node>),
+            },
+            None => Some(Rc::new(IfNode {
+                cond,
+                block,
+                else_block,
+                coords: self.coords.clone(),
+            })),
+        }
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let else_label = generator.next_label();
+        let end_label = generator.next_label();
+
+        self.cond.generate_jump_if_false(generator, else_label.clone());
+        self.block.generate(generator);
+
+        match self.else_block {
+            Some(ref block) => {
+                generator.emit(Instr::Jump(end_label.clone()));
+                generator.emit_label(else_label);
+                block.generate(generator);
+            }
+            None => {
+                generator.emit_label(else_label);
+            }
+        }
+
+        generator.emit_label(end_label);
+    }
 }
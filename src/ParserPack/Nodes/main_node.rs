@@ -2,19 +2,22 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 
 #[derive(Clone)]
 pub struct MainNode {
     pub name: String,
     pub childrens: Vec<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl MainNode {
-    pub fn new(name: String) -> MainNode {
+    pub fn new(name: String, coords: Point) -> MainNode {
         MainNode {
             name: name,
             childrens: vec![],
+            coords,
         }
     }
 }
@@ -45,16 +48,22 @@ impl Node for MainNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.childrens = fold_nodes(&self.childrens);
+        Some(Rc::new(e))
+    }
 
     fn generate(&self, generator: &mut Generator) {
-        let command = Command::create_decl_function("main".to_string());
-        generator.push_to_text_section(command);
+        generator.begin_main();
 
         for child in &self.childrens {
             child.generate(generator);
         }
-
-        let command = Command::create_ret(0);
-        generator.push_to_text_section(command);
+        generator.end_main();
     }
 }
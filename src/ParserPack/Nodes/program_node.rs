@@ -2,19 +2,22 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 
 #[derive(Clone)]
 pub struct ProgramNode {
     pub name: String,
     pub childrens: Vec<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl ProgramNode {
-    pub fn new(name: String) -> ProgramNode {
+    pub fn new(name: String, coords: Point) -> ProgramNode {
         ProgramNode {
             name: name,
             childrens: vec![],
+            coords,
         }
     }
 }
@@ -45,6 +48,15 @@ impl Node for ProgramNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.childrens = fold_nodes(&self.childrens);
+        Some(Rc::new(e))
+    }
 
     fn generate(&self, generator: &mut Generator) {
         for child in &self.childrens {
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 use support::*;
@@ -11,15 +12,17 @@ pub struct RecordFieldNode {
     pub field_name: String,
     pub parent: Rc<Node>,
     pub self_type: Rc<Type>,
+    pub coords: Point,
 }
 
 impl RecordFieldNode {
-    pub fn new(parent: Rc<Node>, field_name: String) -> Result<RecordFieldNode, SemanticErrors> {
+    pub fn new(parent: Rc<Node>, field_name: String, coords: Point) -> Result<RecordFieldNode, SemanticErrors> {
         let self_type = try!(parent.get_type().unwrap().get_by_field(field_name.clone()));
         Ok(RecordFieldNode {
             parent,
             field_name,
             self_type,
+            coords,
         })
     }
 }
@@ -36,7 +39,7 @@ impl PrintableNode for RecordFieldNode {
         vec![self.parent.as_printable()]
     }
     fn get_caption(&self) -> String {
-        self.get_name() + " : " + &self.self_type.as_str() + " = " + &self.self_type.value_as_str()
+        self.get_name() + " : " + &self.self_type.as_str() + " = " + &self.self_type.value_as_str(&self.get_const())
     }
 }
 
@@ -53,4 +56,33 @@ impl Node for RecordFieldNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.parent = fold_node(&self.parent);
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        self.generate_address(generator);
+        generator.emit(Instr::Load(ir_type(self.get_type()), Place::Stack));
+    }
+
+    fn is_addressable(&self) -> bool {
+        self.parent.is_addressable()
+    }
+
+    fn generate_address(&self, generator: &mut Generator) {
+        self.parent.generate_address(generator);
+
+        let word_size = generator.backend.word_size() as i64;
+        let parent_type = self.parent.get_type().unwrap();
+        let offset = parent_type.get_field_offset(self.field_name.clone(), word_size);
+        if offset != 0 {
+            generator.emit(Instr::AddConst(offset));
+        }
+    }
 }
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 use std::collections::HashMap;
@@ -12,14 +13,16 @@ pub struct RecordNode {
     pub self_type: Rc<Type>,
 
     pub fields: Vec<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl RecordNode {
-    pub fn new(name: String, fields: Vec<Rc<Node>>) -> RecordNode {
+    pub fn new(name: String, fields: Vec<Rc<Node>>, coords: Point) -> RecordNode {
         let mut res = RecordNode {
             name,
-            self_type: Rc::new(IntegerType::new(0)),
+            self_type: Rc::new(IntegerType::new()),
             fields,
+            coords,
         };
         res.culc_type();
 
@@ -63,4 +66,7 @@ impl Node for RecordNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
 }
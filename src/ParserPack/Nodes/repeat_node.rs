@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use support::*;
 
@@ -10,20 +11,19 @@ pub struct RepeatNode {
     pub cond: Rc<Node>,
 
     pub block: Rc<Node>,
+    pub coords: Point,
 }
 
 impl RepeatNode {
-    pub fn new(cond: Rc<Node>, block: Rc<Node>) -> Result<RepeatNode, SemanticErrors> {
-        if !cond.get_type()
-            .unwrap()
-            .as_enum("boolean".to_string())
-            .is_none()
-        {
-            Ok(RepeatNode { cond, block })
-        } else {
-            Err(SemanticErrors::OtherError {
-                msg: "Ожидалось логическое выражение".to_string(),
+    pub fn new(cond: Rc<Node>, block: Rc<Node>, coords: Point) -> Result<RepeatNode, SemanticErrors> {
+        if is_boolean_type(cond.get_type()) {
+            Ok(RepeatNode {
+                cond,
+                block,
+                coords,
             })
+        } else {
+            Err(SemanticErrors::ExpectedBoolean)
         }
     }
 }
@@ -54,4 +54,30 @@ impl Node for RepeatNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.cond = fold_node(&self.cond);
+        e.block = fold_node(&self.block);
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let body_label = generator.next_label();
+        let continue_label = generator.next_label();
+        let break_label = generator.next_label();
+
+        generator.emit_label(body_label.clone());
+
+        generator.push_loop(continue_label.clone(), break_label.clone());
+        self.block.generate(generator);
+        generator.pop_loop();
+
+        generator.emit_label(continue_label);
+        self.cond.generate_jump_if_false(generator, body_label);
+        generator.emit_label(break_label);
+    }
 }
@@ -2,6 +2,7 @@ use std::fmt::Display;
 use std::rc::Rc;
 use ParserPack::*;
 use GeneratorPack::*;
+use TokenizerPack::support::Point;
 
 pub enum KindIdentifier {
     Var,
@@ -20,6 +21,10 @@ pub trait Node: PrintableNode + Display {
     fn get_type(&self) -> Option<Rc<Type>> {
         None
     }
+    // Значение, известное при компиляции
+    fn get_const(&self) -> ConstValue {
+        ConstValue::Varying
+    }
     fn get_name(&self) -> String {
         "".to_string()
     }
@@ -27,8 +32,27 @@ pub trait Node: PrintableNode + Display {
         KindIdentifier::Other
     }
 
+    fn get_coords(&self) -> Point;
+
     fn as_printable(&self) -> &PrintableNode;
+    // Свертка констант: новый узел вместо этого или None, если менять нечего
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        None
+    }
+
+    // Есть ли у значения адрес: только такие узлы передаются в var-параметр и стоят слева от :=
+    fn is_addressable(&self) -> bool {
+        false
+    }
+
     fn generate(&self, _generator: &mut Generator) {}
+    fn generate_address(&self, _generator: &mut Generator) {
+        unreachable!("адрес есть только у переменных, элементов массивов и полей записей")
+    }
+    fn generate_jump_if_false(&self, generator: &mut Generator, label: String) {
+        self.generate(generator);
+        generator.emit(Instr::JumpIfFalse(label));
+    }
 }
 
 pub trait PrintableNode {
@@ -59,3 +83,63 @@ pub trait PrintableNode {
         ans
     }
 }
+
+pub fn fold_node(node: &Rc<Node>) -> Rc<Node> {
+    match node.fold_constants() {
+        Some(res) => res,
+        None => node.clone(),
+    }
+}
+
+pub fn fold_nodes(nodes: &Vec<Rc<Node>>) -> Vec<Rc<Node>> {
+    nodes.iter().map(|node| fold_node(node)).collect()
+}
+
+// Выражение с известным значением заменяется литералом
+pub fn fold_to_const(self_type: Rc<Type>, value: &ConstValue, coords: Point) -> Option<Rc<Node>> {
+    if !value.is_known() {
+        return None;
+    }
+    Some(Rc::new(ConstNode::folded(self_type, value.clone(), coords)) as Rc<Node>)
+}
+
+// Тип аргумента вызова; у значения без адреса вид RValue, и var-параметр его не примет
+pub fn arg_type(arg: &Rc<Node>) -> Rc<Type> {
+    let arg_type = arg.get_type().unwrap();
+    if arg.is_addressable() {
+        return arg_type;
+    }
+
+    let res = arg_type.get_clone();
+    res.set_kind(TypeKind::RValue);
+    res
+}
+
+pub fn is_double_type(value_type: Option<Rc<Type>>) -> bool {
+    match value_type {
+        Some(res) => res.get_variant() == ValueVariant::Double,
+        None => false,
+    }
+}
+
+pub fn ir_type(value_type: Option<Rc<Type>>) -> IrType {
+    if is_double_type(value_type) {
+        IrType::Double
+    } else {
+        IrType::Int
+    }
+}
+
+pub fn storage_size(generator: &Generator, value_type: Rc<Type>) -> i32 {
+    value_type.get_storage_size(generator.backend.word_size() as i64) as i32
+}
+
+pub fn is_boolean_type(value_type: Option<Rc<Type>>) -> bool {
+    match value_type {
+        Some(res) => match res.get_variant() {
+            ValueVariant::Enum { name } => name == "boolean",
+            _ => false,
+        },
+        None => false,
+    }
+}
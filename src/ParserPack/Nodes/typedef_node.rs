@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use ParserPack::*;
 
@@ -9,11 +10,16 @@ use ParserPack::*;
 pub struct TypedefNode {
     pub name: String,
     pub self_type: Rc<Type>,
+    pub coords: Point,
 }
 
 impl TypedefNode {
-    pub fn new(name: String, self_type: Rc<Type>) -> TypedefNode {
-        TypedefNode { name, self_type }
+    pub fn new(name: String, self_type: Rc<Type>, coords: Point) -> TypedefNode {
+        TypedefNode {
+            name,
+            self_type,
+            coords,
+        }
     }
 }
 
@@ -46,4 +52,7 @@ impl Node for TypedefNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
 }
@@ -13,6 +13,7 @@ pub struct UnaryOpNode {
     pub op: Token,
     pub children: Rc<Node>,
     pub self_type: Rc<Type>,
+    pub value: ConstValue,
 }
 
 impl UnaryOpNode {
@@ -21,18 +22,22 @@ impl UnaryOpNode {
             TokenType::TPlus => UnarOperation::Plus,
             TokenType::TMinus => UnarOperation::Minus,
             TokenType::TNot => UnarOperation::Not,
+            TokenType::TId if op.text == "succ" => UnarOperation::Succ,
+            TokenType::TId if op.text == "pred" => UnarOperation::Pred,
+            TokenType::TId if op.text == "ord" => UnarOperation::Ord,
             _ => {
-                return Err(SemanticErrors::OtherError {
-                    msg: "Ожидалось + - not".to_string(),
-                })
+                return Err(SemanticErrors::ExpectedUnarOperator)
             }
         };
 
-        let self_type = try!(children.get_type().unwrap().unar_operation(op_type));
+        let child_type = children.get_type().unwrap();
+        let self_type = try!(child_type.unar_operation(op_type));
+        let value = child_type.fold_unar(op_type, &children.get_const());
         Ok(UnaryOpNode {
             op,
             children,
-            self_type: self_type.clone(),
+            self_type,
+            value,
         })
     }
 }
@@ -50,7 +55,7 @@ impl PrintableNode for UnaryOpNode {
     }
     fn get_caption(&self) -> String {
         self.op.text.to_string() + " : " + &self.self_type.as_str() + " = "
-            + &self.self_type.value_as_str()
+            + &self.self_type.value_as_str(&self.value)
     }
 }
 
@@ -58,6 +63,9 @@ impl Node for UnaryOpNode {
     fn get_type(&self) -> Option<Rc<Type>> {
         Some(self.self_type.clone())
     }
+    fn get_const(&self) -> ConstValue {
+        self.value.clone()
+    }
     fn get_name(&self) -> String {
         self.op.value.to_string()
     }
@@ -67,22 +75,53 @@ impl Node for UnaryOpNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.op.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let folded = fold_to_const(self.self_type.clone(), &self.value, self.op.coords.clone());
+        if folded.is_some() {
+            return folded;
+        }
+        let mut e = self.clone();
+        e.children = fold_node(&self.children);
+        Some(Rc::new(e))
+    }
 
     fn generate(&self, generator: &mut Generator) {
-        match self.self_type.get_value() {
-            ValueVariant::Int { v } => {
+        // succ, pred и ord работают с порядковым номером, он же значение на стеке
+        if self.op.token_type == TokenType::TId {
+            self.children.generate(generator);
+            let shift = match &*self.op.text {
+                "succ" => 1,
+                "pred" => -1,
+                _ => return,
+            };
+            generator.emit(Instr::AddConst(shift));
+            if self.self_type.get_variant() == ValueVariant::Int {
+                generator.check_overflow(self.op.coords.y);
+            }
+            return;
+        }
+
+        match self.self_type.get_variant() {
+            ValueVariant::Int => {
                 self.children.generate(generator);
-                let command = Command::create_int_unar_op(self.op.clone().token_type);
-                generator.push_to_text_section(command);
+                generator.emit(Instr::Unary(IrType::Int, self.op.clone().token_type));
+                if self.op.token_type == TokenType::TMinus {
+                    generator.check_overflow(self.op.coords.y);
+                }
             }
-            ValueVariant::Double { v } => {
+            ValueVariant::Double => {
                 self.children.generate(generator);
-                generator.push_to_text_section(Command::create_push_to_fld());
-
-                let command = Command::create_float_unar_op(self.op.clone().token_type);
-                generator.push_to_text_section(command);
+                generator.emit(Instr::ToFloatUnit(IrType::Double));
+                generator.emit(Instr::Unary(IrType::Double, self.op.clone().token_type));
+            }
+            ValueVariant::Enum { .. } => {
+                self.children.generate(generator);
+                generator.emit(Instr::BoolNot);
             }
-            ValueVariant::Enum { name, v } => {}
             _ => {}
         }
     }
@@ -2,6 +2,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use support::*;
 
@@ -10,20 +11,19 @@ pub struct WhileNode {
     pub cond: Rc<Node>,
 
     pub block: Rc<Node>,
+    pub coords: Point,
 }
 
 impl WhileNode {
-    pub fn new(cond: Rc<Node>, block: Rc<Node>) -> Result<WhileNode, SemanticErrors> {
-        if !cond.get_type()
-            .unwrap()
-            .as_enum("boolean".to_string())
-            .is_none()
-        {
-            Ok(WhileNode { cond, block })
-        } else {
-            Err(SemanticErrors::OtherError {
-                msg: "Ожидалось логическое выражение".to_string(),
+    pub fn new(cond: Rc<Node>, block: Rc<Node>, coords: Point) -> Result<WhileNode, SemanticErrors> {
+        if is_boolean_type(cond.get_type()) {
+            Ok(WhileNode {
+                cond,
+                block,
+                coords,
             })
+        } else {
+            Err(SemanticErrors::ExpectedBoolean)
         }
     }
 }
@@ -54,4 +54,29 @@ impl Node for WhileNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.cond = fold_node(&self.cond);
+        e.block = fold_node(&self.block);
+        Some(Rc::new(e))
+    }
+
+    fn generate(&self, generator: &mut Generator) {
+        let continue_label = generator.next_label();
+        let break_label = generator.next_label();
+
+        generator.emit_label(continue_label.clone());
+        self.cond.generate_jump_if_false(generator, break_label.clone());
+
+        generator.push_loop(continue_label.clone(), break_label.clone());
+        self.block.generate(generator);
+        generator.pop_loop();
+
+        generator.emit(Instr::Jump(continue_label));
+        generator.emit_label(break_label);
+    }
 }
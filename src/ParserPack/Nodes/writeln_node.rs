@@ -3,6 +3,7 @@ use GeneratorPack::*;
 use std::fmt;
 use std::fmt::Display;
 use std::rc::Rc;
+use TokenizerPack::support::Point;
 use ParserPack::Nodes::support::*;
 use support::*;
 use ParserPack::*;
@@ -10,11 +11,12 @@ use ParserPack::*;
 #[derive(Clone)]
 pub struct WritelnNode {
     pub args: Vec<Rc<Node>>,
+    pub coords: Point,
 }
 
 impl WritelnNode {
-    pub fn new(args: Vec<Rc<Node>>) -> Result<WritelnNode, SemanticErrors> {
-        Ok(WritelnNode { args })
+    pub fn new(args: Vec<Rc<Node>>, coords: Point) -> Result<WritelnNode, SemanticErrors> {
+        Ok(WritelnNode { args, coords })
     }
 }
 
@@ -48,25 +50,38 @@ impl Node for WritelnNode {
     fn as_printable(&self) -> &PrintableNode {
         self
     }
+    fn get_coords(&self) -> Point {
+        self.coords.clone()
+    }
+
+    fn fold_constants(&self) -> Option<Rc<Node>> {
+        let mut e = self.clone();
+        e.args = fold_nodes(&self.args);
+        Some(Rc::new(e))
+    }
 
     fn generate(&self, generator: &mut Generator) {
         let mut format = "".to_string();
-        let mut fcount = 0;
+        let mut args = vec![];
 
         for arg in &self.args {
             let ttype = arg.get_type().unwrap();
-            match ttype.get_value() {
-                ValueVariant::Int { v } => {
-                    format += "%d";
+            match ttype.get_variant() {
+                ValueVariant::Int => {
+                    format += &generator.backend.int_format();
                 }
-                ValueVariant::Double { v } => {
+                ValueVariant::Double => {
                     format += "%f";
                 }
-                ValueVariant::Char { v } => {
+                ValueVariant::Char => {
                     format += "%c";
                 }
-                ValueVariant::Enum { name, v } => {
-                    format += "%d";
+                ValueVariant::Enum { name } => {
+                    if name == "boolean" {
+                        format += "%s";
+                    } else {
+                        format += &generator.backend.int_format();
+                    }
                 }
                 ValueVariant::Other => {}
             }
@@ -75,27 +90,24 @@ impl Node for WritelnNode {
         for arg in self.args.clone().iter().rev() {
             arg.generate(generator);
 
-            match arg.get_type().unwrap().get_value() {
-                ValueVariant::Double { v } => {
-                    fcount += 1;
-                    generator.push_to_text_section(Command::create_float_to_double());
-                }
-                _ => {}
+            if is_boolean_type(arg.get_type()) {
+                let label = generator.bool_names();
+                generator.emit(Instr::BoolToStr(label));
             }
         }
+        for arg in &self.args {
+            args.push(ir_type(arg.get_type()));
+        }
 
         let format_name = generator.next_format();
 
-        let command = Command::create_format_string(format_name.clone(), format);
-        generator.push_to_data_section(command);
-
-        let command = Command::create_push_global_var(format_name);
-        generator.push_to_text_section(command);
-
-        let command = Command::create_call_func("_printf".to_string());
-        generator.push_to_text_section(command);
-
-        let command = Command::create_clear_stack(4 * ((self.args.len() + fcount + 1) as i32));
-        generator.push_to_text_section(command);
+        generator.emit_data(IrData::Format {
+            name: format_name.clone(),
+            text: format,
+        });
+        generator.emit(Instr::CallPrintf {
+            format: format_name,
+            args,
+        });
     }
 }
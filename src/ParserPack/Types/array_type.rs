@@ -5,49 +5,44 @@ use TokenizerPack::support::*;
 use std::cell::Cell;
 
 pub struct ArrayType {
-    elements: Vec<Rc<Type>>,
+    count: i64,
 
     index_type: Rc<Type>,
     out_type: Rc<Type>,
 
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl ArrayType {
-    pub fn new(index_type: Rc<Type>, out_type: Rc<Type>) -> Result<ArrayType, CompilerErrors> {
+    pub fn new(index_type: Rc<Type>, out_type: Rc<Type>) -> Result<ArrayType, SemanticErrors> {
         if !index_type.is_enumerated() {
-            return Err(CompilerErrors::from(SemanticErrors::OtherError {
-                msg: "Ожидался перечислимый тип".to_string(),
-            }));
+            return Err(SemanticErrors::ExpectedEnumerated);
         }
         if (index_type.get_right() as i64 - index_type.get_left() as i64) * out_type.get_size()
             > MAX_SIZE
         {
-            return Err(CompilerErrors::from(SemanticErrors::OtherError {
-                msg: "Слишком много элементов массива".to_string(),
-            }));
-        }
-
-        let count = index_type.get_right() as i64 - index_type.get_left() as i64;
-        let mut elements: Vec<Rc<Type>> = vec![];
-        for _i in 0..count + 1 {
-            elements.push(out_type.get_clone());
+            return Err(SemanticErrors::ArrayTooLarge);
         }
 
+        let count = index_type.get_right() as i64 - index_type.get_left() as i64 + 1;
         Ok(ArrayType {
             index_type,
             out_type,
-            elements,
+            count,
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
         })
     }
 }
 
 impl Type for ArrayType {
     fn get_size(&self) -> i64 {
-        self.elements.len() as i64 * self.out_type.get_size()
+        self.count * self.out_type.get_size()
+    }
+    fn get_storage_size(&self, word_size: i64) -> i64 {
+        self.count * self.out_type.get_storage_size(word_size)
+    }
+    fn get_index_type(&self) -> Option<Rc<Type>> {
+        Some(self.index_type.clone())
     }
     fn as_str(&self) -> String {
         let ans =
@@ -55,80 +50,61 @@ impl Type for ArrayType {
         ans
     }
 
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
-    }
-
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
-        }
-        let mut ans = "[".to_string();
-        for element in &self.elements {
-            ans += &(element.value_as_str() + ", ");
+    fn get_by_index(&self, index: Rc<Type>, value: &ConstValue) -> Result<Rc<Type>, SemanticErrors> {
+        let matches = match (index.get_variant(), self.index_type.get_variant()) {
+            (ValueVariant::Int, ValueVariant::Int) | (ValueVariant::Char, ValueVariant::Char) => {
+                true
+            }
+            (ValueVariant::Enum { name: l }, ValueVariant::Enum { name: r }) => l == r,
+            _ => false,
+        };
+        if !matches {
+            return Err(SemanticErrors::InvalidIndexType {
+                index: index.as_str(),
+                expected: self.index_type.as_str(),
+            });
         }
-        (ans[..ans.len() - 2].to_string() + "]")
-    }
 
-    fn get_by_index(&self, index: Rc<Type>) -> Result<Rc<Type>, SemanticErrors> {
-        let ind = match index.get_value() {
-            ValueVariant::Int { v } => match self.index_type.as_integer() {
-                Some(_res) => v,
-                None => {
-                    return Err(self.create_err(format!(
-                        "Неверный тип индекса {}, ожидался {}",
-                        index.as_str(),
-                        self.index_type.as_str()
-                    )))
-                }
-            },
-            ValueVariant::Enum { name, v } => match self.index_type.as_enum(name) {
-                Some(_res) => v,
-                None => {
-                    return Err(self.create_err(format!(
-                        "Неверный тип индекса {}, ожидался {}",
-                        index.as_str(),
-                        self.index_type.as_str()
-                    )))
-                }
-            },
-            _ => {
-                return Err(self.create_err(format!(
-                    "Неверный тип индекса {}",
-                    index.as_str()
-                )))
+        // индекс известен при компиляции: проверяем границы сразу
+        if let Some(ind) = value.as_index() {
+            let pos = ind - self.index_type.get_left() as i64;
+            if pos < 0 || pos >= self.count {
+                return Err(SemanticErrors::IndexOutOfRange {
+                    index: index.value_as_str(value),
+                    expected: self.index_type.as_str(),
+                });
             }
-        };
-        Ok(self.elements[ind as usize].clone())
+        }
+        Ok(self.out_type.clone())
     }
 
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
+    fn parse_init_value(&self, parser: &mut Parser) -> Result<ConstValue, CompilerErrors> {
         check_token!(parser, TokenType::TObr);
-        for i in 0..self.elements.len() {
-            try!(self.elements[i].parse_init_value(parser));
-            if i < self.elements.len() - 1 {
+        let mut items = vec![];
+        for i in 0..self.count {
+            push_init_value(&mut items, try!(parser.parse_init_value(self.out_type.clone())));
+            if i < self.count - 1 {
                 check_token!(parser, TokenType::TComma);
             }
         }
         check_token!(parser, TokenType::TCbr);
-        Ok("Array".to_string())
+        Ok(ConstValue::Aggregate(items))
     }
 
     fn set_kind(&self, kind: TypeKind) {
-        for elem in &self.elements {
-            elem.set_kind(kind.clone());
-        }
+        self.out_type.set_kind(kind.clone());
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
     fn get_clone(&self) -> Rc<Type> {
-        let clone = match ArrayType::new(self.index_type.clone(), self.out_type.clone()) {
-            Ok(res) => res,
-            Err(_err) => panic!(),
-        };
-        Rc::new(clone)
+        Rc::new(ArrayType {
+            count: self.count,
+            index_type: self.index_type.clone(),
+            out_type: self.out_type.get_clone(),
+            kind: self.kind.clone(),
+        })
     }
 
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
@@ -138,4 +114,21 @@ impl Type for ArrayType {
     fn cast_to(&self, other: Rc<Type>) -> Result<Rc<Type>, SemanticErrors> {
         other.cast_from_array(self)
     }
+
+    fn cast_from_array(&self, other: &ArrayType) -> Result<Rc<Type>, SemanticErrors> {
+        if !kind_cast(&other.kind.get(), &self.kind.get()) {
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
+        }
+
+        if other.as_str() != self.as_str() {
+            return Err(SemanticErrors::CastError {
+                this: other.as_str(),
+                other: self.as_str(),
+            });
+        }
+        Ok(other.get_clone())
+    }
 }
@@ -4,17 +4,13 @@ use std::cell::Cell;
 use support::*;
 
 pub struct CharType {
-    value: Cell<u8>,
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl CharType {
-    pub fn new(value: u8) -> CharType {
+    pub fn new() -> CharType {
         CharType {
-            value: Cell::new(value),
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
         }
     }
 }
@@ -26,57 +22,14 @@ impl Type for CharType {
     fn as_str(&self) -> String {
         "Char".to_string()
     }
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
-        }
-        "#".to_string() + &self.value.get().to_string()
-    }
-
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
-    }
-
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
-        let expr = try!(parser.parse_simple_expr());
-        let value = expr.get_type().unwrap();
-        try!(self.set_value(value));
-        Ok(self.value.get().to_string())
-    }
 
-    fn as_char(&self) -> Option<u8> {
-        Some(self.value.get())
+    fn get_variant(&self) -> ValueVariant {
+        ValueVariant::Char
     }
-
-    fn get_value(&self) -> ValueVariant {
-        ValueVariant::Char {
-            v: self.value.get(),
+    fn check_assign(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
+        if !is_mutable_kind(&self.kind.get()) || value.get_variant() != ValueVariant::Char {
+            return Err(cant_assign(&value, self.as_str()));
         }
-    }
-    fn set_value(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
-        if !is_mutable_kind(&self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                value.as_str(),
-                self.as_str()
-            )));
-        }
-
-        let new_value = match value.as_char() {
-            Some(res) => res,
-            None => {
-                return Err(self.create_err(format!(
-                    "Невозможно привести {} к {}",
-                    value.as_str(),
-                    self.as_str()
-                )))
-            }
-        };
-        self.value.set(new_value);
-        self.is_unknown.set(value.get_unknown());
         Ok("Ok".to_string())
     }
 
@@ -90,12 +43,26 @@ impl Type for CharType {
         127
     }
     fn get_clone(&self) -> Rc<Type> {
-        Rc::new(CharType::new(self.value.get()))
+        Rc::new(CharType::new())
     }
 
     fn set_kind(&self, kind: TypeKind) {
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
+
+    fn unar_operation(&self, op: UnarOperation) -> Result<Rc<Type>, SemanticErrors> {
+        match op {
+            UnarOperation::Succ | UnarOperation::Pred => Ok(Rc::new(CharType::new()) as Rc<Type>),
+            UnarOperation::Ord => Ok(Rc::new(IntegerType::new()) as Rc<Type>),
+            _ => Err(SemanticErrors::ErrorInUnarOperation {
+                name: self.as_str(),
+                op,
+            }),
+        }
+    }
 
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
         other.bin_operation_char_type(self, op)
@@ -106,12 +73,11 @@ impl Type for CharType {
     }
     fn cast_from_char(&self, other: &CharType) -> Result<Rc<Type>, SemanticErrors> {
         if !kind_cast(&other.kind.get(), &self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                other.as_str(),
-                self.as_str()
-            )));
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
         }
-        Ok(Rc::new(CharType::new(other.value.get())))
+        Ok(Rc::new(CharType::new()))
     }
 }
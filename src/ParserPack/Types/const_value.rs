@@ -0,0 +1,194 @@
+use ParserPack::*;
+
+// Значение выражения, известное при компиляции.
+// Решетка: Undefined (значения еще нет) <= константа <= Varying (зависит от выполнения)
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstValue {
+    Undefined,
+    Int(i64),
+    Double(f64),
+    Char(u8),
+    // v - номер варианта в перечислении
+    Enum { name: String, v: i64 },
+    // начальное значение массива или записи: скаляры в порядке размещения в памяти
+    Aggregate(Vec<ConstValue>),
+    Varying,
+}
+
+impl ConstValue {
+    pub fn boolean(res: bool) -> ConstValue {
+        ConstValue::Enum {
+            name: "boolean".to_string(),
+            v: res as i64,
+        }
+    }
+
+    pub fn is_known(&self) -> bool {
+        match *self {
+            // массив или запись не сворачивается в литерал
+            ConstValue::Undefined | ConstValue::Varying | ConstValue::Aggregate(..) => false,
+            _ => true,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i64> {
+        match *self {
+            ConstValue::Int(v) => Some(v),
+            _ => None,
+        }
+    }
+    pub fn as_double(&self) -> Option<f64> {
+        match *self {
+            ConstValue::Int(v) => Some(v as f64),
+            ConstValue::Double(v) => Some(v),
+            _ => None,
+        }
+    }
+    pub fn as_char(&self) -> Option<u8> {
+        match *self {
+            ConstValue::Char(v) => Some(v),
+            _ => None,
+        }
+    }
+    pub fn as_enum_without_name(&self) -> Option<i64> {
+        match *self {
+            ConstValue::Enum { v, .. } => Some(v),
+            _ => None,
+        }
+    }
+    pub fn as_boolean(&self) -> Option<bool> {
+        match *self {
+            ConstValue::Enum { ref name, v } if name == "boolean" => Some(v != 0),
+            _ => None,
+        }
+    }
+    // Номер значения среди значений своего типа: для перечислений - номер варианта
+    pub fn as_index(&self) -> Option<i64> {
+        match *self {
+            ConstValue::Int(v) => Some(v),
+            ConstValue::Char(v) => Some(v as i64),
+            ConstValue::Enum { v, .. } => Some(v),
+            _ => None,
+        }
+    }
+
+    // Приведение при присваивании переменной типа variant
+    pub fn widen_to(&self, variant: &ValueVariant) -> ConstValue {
+        match (self, variant) {
+            (&ConstValue::Int(v), &ValueVariant::Double) => ConstValue::Double(v as f64),
+            _ => self.clone(),
+        }
+    }
+
+    pub fn as_str(&self) -> String {
+        match *self {
+            ConstValue::Undefined => "Undefined".to_string(),
+            ConstValue::Int(v) => v.to_string(),
+            ConstValue::Double(v) => v.to_string(),
+            ConstValue::Char(v) => "#".to_string() + &v.to_string(),
+            ConstValue::Enum { v, .. } => v.to_string(),
+            ConstValue::Aggregate(ref items) => {
+                let items: Vec<String> = items.iter().map(|item| item.as_str()).collect();
+                "[".to_string() + &items.join(", ") + "]"
+            }
+            ConstValue::Varying => "Unknown".to_string(),
+        }
+    }
+
+    pub fn fold_unar(&self, op: UnarOperation) -> ConstValue {
+        match (op, self) {
+            (UnarOperation::Plus, &ConstValue::Int(v)) => ConstValue::Int(v),
+            (UnarOperation::Minus, &ConstValue::Int(v)) => varying_if_none(v.checked_neg()),
+            (UnarOperation::Not, &ConstValue::Int(v)) => ConstValue::Int(!v),
+            (UnarOperation::Succ, &ConstValue::Int(v)) => varying_if_none(v.checked_add(1)),
+            (UnarOperation::Pred, &ConstValue::Int(v)) => varying_if_none(v.checked_sub(1)),
+            (UnarOperation::Ord, &ConstValue::Int(v)) => ConstValue::Int(v),
+
+            (UnarOperation::Plus, &ConstValue::Double(v)) => ConstValue::Double(v),
+            (UnarOperation::Minus, &ConstValue::Double(v)) => ConstValue::Double(-v),
+
+            (UnarOperation::Succ, &ConstValue::Char(v)) => ConstValue::Char(v.wrapping_add(1)),
+            (UnarOperation::Pred, &ConstValue::Char(v)) => ConstValue::Char(v.wrapping_sub(1)),
+            (UnarOperation::Ord, &ConstValue::Char(v)) => ConstValue::Int(v as i64),
+
+            (UnarOperation::Not, _) if self.as_boolean().is_some() => {
+                ConstValue::boolean(!self.as_boolean().unwrap())
+            }
+            _ => ConstValue::Varying,
+        }
+    }
+
+    pub fn fold_bin(op: BinOperation, left: &ConstValue, right: &ConstValue) -> ConstValue {
+        match (left, right) {
+            (&ConstValue::Int(l), &ConstValue::Int(r)) => fold_int(op, l, r),
+            (&ConstValue::Int(_), &ConstValue::Double(_))
+            | (&ConstValue::Double(_), &ConstValue::Int(_))
+            | (&ConstValue::Double(_), &ConstValue::Double(_)) => {
+                fold_double(op, left.as_double().unwrap(), right.as_double().unwrap())
+            }
+            (&ConstValue::Enum { v: l, .. }, &ConstValue::Enum { v: r, .. }) => fold_enum(op, l, r),
+            (&ConstValue::Char(l), &ConstValue::Char(r)) => fold_enum(op, l as i64, r as i64),
+            _ => ConstValue::Varying,
+        }
+    }
+}
+
+// Деление на ноль или переполнение не вычисляются на этапе компиляции;
+// результат должен помещаться в 32 бита, как на самой узкой целевой платформе
+fn varying_if_none(value: Option<i64>) -> ConstValue {
+    match value {
+        Some(v) if v as i32 as i64 == v => ConstValue::Int(v),
+        _ => ConstValue::Varying,
+    }
+}
+
+fn fold_int(op: BinOperation, l: i64, r: i64) -> ConstValue {
+    match op {
+        BinOperation::Plus => varying_if_none(l.checked_add(r)),
+        BinOperation::Minus => varying_if_none(l.checked_sub(r)),
+        BinOperation::Mul => varying_if_none(l.checked_mul(r)),
+        // / всегда вещественное, даже над целыми
+        BinOperation::Share if r != 0 => ConstValue::Double(l as f64 / r as f64),
+        BinOperation::Share => ConstValue::Varying,
+        BinOperation::Div => varying_if_none(l.checked_div(r)),
+        BinOperation::Mod => varying_if_none(l.checked_rem(r)),
+        BinOperation::And => ConstValue::Int(l & r),
+        BinOperation::Or => ConstValue::Int(l | r),
+        BinOperation::OGe => ConstValue::boolean(l >= r),
+        BinOperation::OGt => ConstValue::boolean(l > r),
+        BinOperation::OEq => ConstValue::boolean(l == r),
+        BinOperation::OLe => ConstValue::boolean(l <= r),
+        BinOperation::OLt => ConstValue::boolean(l < r),
+        BinOperation::ONe => ConstValue::boolean(l != r),
+    }
+}
+
+fn fold_double(op: BinOperation, l: f64, r: f64) -> ConstValue {
+    match op {
+        BinOperation::Plus => ConstValue::Double(l + r),
+        BinOperation::Minus => ConstValue::Double(l - r),
+        BinOperation::Mul => ConstValue::Double(l * r),
+        BinOperation::Share => ConstValue::Double(l / r),
+        BinOperation::OGe => ConstValue::boolean(l >= r),
+        BinOperation::OGt => ConstValue::boolean(l > r),
+        BinOperation::OEq => ConstValue::boolean(l == r),
+        BinOperation::OLe => ConstValue::boolean(l <= r),
+        BinOperation::OLt => ConstValue::boolean(l < r),
+        BinOperation::ONe => ConstValue::boolean(l != r),
+        _ => ConstValue::Varying,
+    }
+}
+
+fn fold_enum(op: BinOperation, l: i64, r: i64) -> ConstValue {
+    match op {
+        BinOperation::And => ConstValue::boolean((l & r) != 0),
+        BinOperation::Or => ConstValue::boolean((l | r) != 0),
+        BinOperation::OGe => ConstValue::boolean(l >= r),
+        BinOperation::OGt => ConstValue::boolean(l > r),
+        BinOperation::OEq => ConstValue::boolean(l == r),
+        BinOperation::OLe => ConstValue::boolean(l <= r),
+        BinOperation::OLt => ConstValue::boolean(l < r),
+        BinOperation::ONe => ConstValue::boolean(l != r),
+        _ => ConstValue::Varying,
+    }
+}
@@ -4,17 +4,13 @@ use std::cell::Cell;
 use support::*;
 
 pub struct DoubleType {
-    pub value: Cell<f64>,
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl DoubleType {
-    pub fn new(value: f64) -> DoubleType {
+    pub fn new() -> DoubleType {
         DoubleType {
-            value: Cell::new(value),
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
         }
     }
 }
@@ -23,73 +19,45 @@ impl Type for DoubleType {
     fn get_size(&self) -> i64 {
         32
     }
+    fn get_storage_size(&self, _word_size: i64) -> i64 {
+        8
+    }
     fn as_str(&self) -> String {
         "Double".to_string()
     }
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
-        }
-        self.value.get().to_string()
-    }
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
-    }
 
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
-        let expr = try!(parser.parse_simple_expr());
-        let value = expr.get_type().unwrap();
-        try!(self.set_value(value));
-        Ok(self.value.get().to_string())
-    }
     fn get_clone(&self) -> Rc<Type> {
-        Rc::new(DoubleType::new(self.value.get()))
-    }
-
-    fn as_double(&self) -> Option<f64> {
-        Some(self.value.get())
+        Rc::new(DoubleType::new())
     }
 
-    fn get_value(&self) -> ValueVariant {
-        ValueVariant::Double {
-            v: self.value.get(),
-        }
+    fn get_variant(&self) -> ValueVariant {
+        ValueVariant::Double
     }
-    fn set_value(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
+    fn check_assign(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
         if !is_mutable_kind(&self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                value.as_str(),
-                self.as_str()
-            )));
+            return Err(cant_assign(&value, self.as_str()));
+        }
+        match value.get_variant() {
+            ValueVariant::Int | ValueVariant::Double => Ok("Ok".to_string()),
+            _ => Err(cant_assign(&value, self.as_str())),
         }
-
-        let new_value = match value.as_double() {
-            Some(res) => res,
-            None => {
-                return Err(self.create_err(format!(
-                    "Невозможно привести {} к {}",
-                    value.as_str(),
-                    self.as_str()
-                )))
-            }
-        };
-        self.value.set(new_value);
-        self.is_unknown.set(value.get_unknown());
-        Ok("Ok".to_string())
     }
     fn set_kind(&self, kind: TypeKind) {
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
 
     fn unar_operation(&self, op: UnarOperation) -> Result<Rc<Type>, SemanticErrors> {
         match op {
-            UnarOperation::Plus => Ok(Rc::new(DoubleType::new(self.value.get())) as Rc<Type>),
-            UnarOperation::Minus => Ok(Rc::new(DoubleType::new(-self.value.get())) as Rc<Type>),
-            UnarOperation::Not => Ok(Rc::new(DoubleType::new(-self.value.get())) as Rc<Type>),
+            UnarOperation::Plus | UnarOperation::Minus | UnarOperation::Not => {
+                Ok(Rc::new(DoubleType::new()) as Rc<Type>)
+            }
+            _ => Err(SemanticErrors::ErrorInUnarOperation {
+                name: self.as_str(),
+                op,
+            }),
         }
     }
 
@@ -101,76 +69,14 @@ impl Type for DoubleType {
         other: &IntegerType,
         op: BinOperation,
     ) -> Result<Rc<Type>, SemanticErrors> {
-        match op {
-            BinOperation::Plus => Ok(Rc::new(DoubleType::new(
-                self.value.get() + other.value.get() as f64,
-            )) as Rc<Type>),
-            BinOperation::Minus => Ok(Rc::new(DoubleType::new(
-                other.value.get() as f64 - self.value.get(),
-            )) as Rc<Type>),
-            BinOperation::Mul => Ok(Rc::new(DoubleType::new(
-                self.value.get() * other.value.get() as f64,
-            )) as Rc<Type>),
-            BinOperation::Share => Ok(Rc::new(DoubleType::new(
-                other.value.get() as f64 / self.value.get(),
-            )) as Rc<Type>),
-            BinOperation::And => Ok(Rc::new(DoubleType::new(
-                self.value.get() * other.value.get() as f64,
-            )) as Rc<Type>),
-            BinOperation::Or => Ok(Rc::new(DoubleType::new(
-                other.value.get() as f64 / self.value.get(),
-            )) as Rc<Type>),
-            BinOperation::OGe => Ok(create_boolean(
-                (other.value.get() as f64) >= self.value.get(),
-            )),
-            BinOperation::OGt => Ok(create_boolean(
-                (other.value.get() as f64) > self.value.get(),
-            )),
-            BinOperation::OEq => Ok(create_boolean(
-                (other.value.get() as f64) == self.value.get(),
-            )),
-            BinOperation::OLe => Ok(create_boolean(
-                (other.value.get() as f64) <= self.value.get(),
-            )),
-            BinOperation::OLt => Ok(create_boolean(
-                (other.value.get() as f64) < self.value.get(),
-            )),
-            BinOperation::ONe => Ok(create_boolean(
-                (other.value.get() as f64) != self.value.get(),
-            )),
-        }
+        double_result(other.as_str(), self.as_str(), op)
     }
     fn bin_operation_double_type(
         &self,
         other: &DoubleType,
         op: BinOperation,
     ) -> Result<Rc<Type>, SemanticErrors> {
-        match op {
-            BinOperation::Plus => {
-                Ok(Rc::new(DoubleType::new(self.value.get() + other.value.get())) as Rc<Type>)
-            }
-            BinOperation::Minus => {
-                Ok(Rc::new(DoubleType::new(other.value.get() - self.value.get())) as Rc<Type>)
-            }
-            BinOperation::Mul => {
-                Ok(Rc::new(DoubleType::new(self.value.get() * other.value.get())) as Rc<Type>)
-            }
-            BinOperation::Share => {
-                Ok(Rc::new(DoubleType::new(other.value.get() / self.value.get())) as Rc<Type>)
-            }
-            BinOperation::And => {
-                Ok(Rc::new(DoubleType::new(self.value.get() * other.value.get())) as Rc<Type>)
-            }
-            BinOperation::Or => {
-                Ok(Rc::new(DoubleType::new(other.value.get() / self.value.get())) as Rc<Type>)
-            }
-            BinOperation::OGe => Ok(create_boolean(other.value.get() >= self.value.get())),
-            BinOperation::OGt => Ok(create_boolean(other.value.get() > self.value.get())),
-            BinOperation::OEq => Ok(create_boolean(other.value.get() == self.value.get())),
-            BinOperation::OLe => Ok(create_boolean(other.value.get() <= self.value.get())),
-            BinOperation::OLt => Ok(create_boolean(other.value.get() < self.value.get())),
-            BinOperation::ONe => Ok(create_boolean(other.value.get() != self.value.get())),
-        }
+        double_result(other.as_str(), self.as_str(), op)
     }
 
     fn cast_to(&self, other: Rc<Type>) -> Result<Rc<Type>, SemanticErrors> {
@@ -179,22 +85,36 @@ impl Type for DoubleType {
 
     fn cast_from_integer(&self, other: &IntegerType) -> Result<Rc<Type>, SemanticErrors> {
         if !kind_cast(&other.kind.get(), &self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                other.as_str(),
-                self.as_str()
-            )));
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
         }
-        Ok(Rc::new(DoubleType::new(other.value.get() as f64)))
+        Ok(Rc::new(DoubleType::new()))
     }
     fn cast_from_double(&self, other: &DoubleType) -> Result<Rc<Type>, SemanticErrors> {
         if !kind_cast(&other.kind.get(), &self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                other.as_str(),
-                self.as_str()
-            )));
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
+        }
+        Ok(Rc::new(DoubleType::new()))
+    }
+}
+
+// Тип результата операции, у которой хотя бы один операнд вещественный
+pub fn double_result(left: String, right: String, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
+    match op {
+        BinOperation::Div | BinOperation::Mod => {
+            Err(SemanticErrors::ErrorInBinOperation { left, right, op })
         }
-        Ok(Rc::new(DoubleType::new(other.value.get())))
+        BinOperation::OGe
+        | BinOperation::OGt
+        | BinOperation::OEq
+        | BinOperation::OLe
+        | BinOperation::OLt
+        | BinOperation::ONe => Ok(create_boolean()),
+        _ => Ok(Rc::new(DoubleType::new()) as Rc<Type>),
     }
 }
@@ -11,10 +11,8 @@ pub struct EnumType {
 
     is_initialized: bool,
     name: String,
-    value: Cell<i64>,
 
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl EnumType {
@@ -22,7 +20,6 @@ impl EnumType {
         variants_list: Vec<String>,
         value_map_init: HashMap<String, i64>,
         name: String,
-        value: i64,
     ) -> EnumType {
         let mut value_map: HashMap<String, i64> = HashMap::new();
         let mut curr_val = 0;
@@ -48,10 +45,26 @@ impl EnumType {
             variants_list,
             value_map,
             name,
-            value: Cell::new(value),
             is_initialized,
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
+        }
+    }
+
+    pub fn variant_value(&self, v: i64) -> ConstValue {
+        ConstValue::Enum {
+            name: self.name.clone(),
+            v,
+        }
+    }
+
+    // Succ и pred определены только для перечислений без явных значений;
+    // выход за последний вариант при компиляции не вычисляется
+    fn shift(&self, delta: i64, value: &ConstValue) -> ConstValue {
+        match value.as_enum_without_name() {
+            Some(v) if v + delta >= 0 && v + delta < self.variants_list.len() as i64 => {
+                self.variant_value(v + delta)
+            }
+            _ => ConstValue::Varying,
         }
     }
 }
@@ -73,32 +86,18 @@ impl Type for EnumType {
         caption[..caption.len() - 2].to_string() + ")"
     }
 
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
-    }
-
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
+    fn value_as_str(&self, value: &ConstValue) -> String {
+        match value.as_enum_without_name() {
+            Some(v) => self.variants_list[v as usize].clone(),
+            None => value.as_str(),
         }
-        self.variants_list[self.value.get() as usize].clone()
     }
 
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
-        let expr = try!(parser.parse_simple_expr());
-        let value = expr.get_type().unwrap();
-        try!(self.set_value(value));
-        Ok(self.value.get().to_string())
-    }
     fn get_clone(&self) -> Rc<Type> {
         Rc::new(EnumType::new(
             self.variants_list.clone(),
             self.value_map.clone(),
             self.name.clone(),
-            self.value.get(),
         ))
     }
 
@@ -112,40 +111,54 @@ impl Type for EnumType {
         self.variants_list.len() as i32 - 1
     }
 
-    fn get_value(&self) -> ValueVariant {
+    fn get_variant(&self) -> ValueVariant {
         ValueVariant::Enum {
             name: self.name.clone(),
-            v: self.value.get(),
         }
     }
-    fn set_value(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
-        let new_value = match value.as_enum(self.name.clone()) {
-            Some(res) => res,
-            None => {
-                return Err(self.create_err(format!(
-                    "Невозможно привести {} к {}",
-                    value.as_str(),
-                    self.as_str()
-                )))
-            }
-        };
-        self.value.set(new_value);
-        self.is_unknown.set(value.get_unknown());
+    fn check_assign(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
+        if !is_mutable_kind(&self.kind.get()) || value.get_variant() != self.get_variant() {
+            return Err(cant_assign(&value, self.as_str()));
+        }
         Ok("Ok".to_string())
     }
     fn set_kind(&self, kind: TypeKind) {
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
 
-    fn as_enum(&self, name: String) -> Option<i64> {
-        if name == self.name {
-            return Some(self.value.get());
+    fn as_ordinal(&self, value: &ConstValue) -> Option<i64> {
+        match value.as_enum_without_name() {
+            Some(v) => self.value_map.get(&self.variants_list[v as usize]).cloned(),
+            None => None,
         }
-        None
     }
 
-    fn as_enum_without_name(&self) -> Option<i64> {
-        Some(self.value.get())
+    fn unar_operation(&self, op: UnarOperation) -> Result<Rc<Type>, SemanticErrors> {
+        match op {
+            UnarOperation::Not if self.name == "boolean" => Ok(create_boolean()),
+            UnarOperation::Succ | UnarOperation::Pred if !self.is_initialized => {
+                Ok(self.get_clone())
+            }
+            UnarOperation::Ord => Ok(Rc::new(IntegerType::new()) as Rc<Type>),
+            _ => Err(SemanticErrors::ErrorInUnarOperation {
+                name: self.as_str(),
+                op,
+            }),
+        }
+    }
+    fn fold_unar(&self, op: UnarOperation, value: &ConstValue) -> ConstValue {
+        match op {
+            UnarOperation::Succ => self.shift(1, value),
+            UnarOperation::Pred => self.shift(-1, value),
+            UnarOperation::Ord => match self.as_ordinal(value) {
+                Some(v) => ConstValue::Int(v),
+                None => ConstValue::Varying,
+            },
+            _ => value.fold_unar(op),
+        }
     }
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
         other.bin_operation_enum_type(self, op)
@@ -156,20 +169,15 @@ impl Type for EnumType {
         other: &EnumType,
         op: BinOperation,
     ) -> Result<Rc<Type>, SemanticErrors> {
-        let vars: Vec<bool> = vec![false, true];
         match op {
-            BinOperation::And => Ok(create_boolean(
-                vars[(other.value.get() & self.value.get()) as usize],
-            )),
-            BinOperation::Or => Ok(create_boolean(
-                vars[(other.value.get() | self.value.get()) as usize],
-            )),
-            BinOperation::OGe => Ok(create_boolean(other.value.get() >= self.value.get())),
-            BinOperation::OGt => Ok(create_boolean(other.value.get() > self.value.get())),
-            BinOperation::OEq => Ok(create_boolean(other.value.get() == self.value.get())),
-            BinOperation::OLe => Ok(create_boolean(other.value.get() <= self.value.get())),
-            BinOperation::OLt => Ok(create_boolean(other.value.get() < self.value.get())),
-            BinOperation::ONe => Ok(create_boolean(other.value.get() != self.value.get())),
+            BinOperation::And
+            | BinOperation::Or
+            | BinOperation::OGe
+            | BinOperation::OGt
+            | BinOperation::OEq
+            | BinOperation::OLe
+            | BinOperation::OLt
+            | BinOperation::ONe => Ok(create_boolean()),
             _ => Err(SemanticErrors::ErrorInBinOperation {
                 left: other.as_str(),
                 right: self.as_str(),
@@ -183,11 +191,10 @@ impl Type for EnumType {
     }
     fn cast_from_enum(&self, other: &EnumType) -> Result<Rc<Type>, SemanticErrors> {
         if !kind_cast(&other.kind.get(), &self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                other.as_str(),
-                self.as_str()
-            )));
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
         }
 
         if other.name != self.name {
@@ -9,6 +9,8 @@ pub struct FunctionType {
 
 impl FunctionType {
     pub fn new(arg_list: Vec<Rc<Type>>, out_type: Rc<Type>) -> FunctionType {
+        // out_type может быть общим с объявлением типа, поэтому вид меняется у копии
+        let out_type = out_type.get_clone();
         out_type.set_kind(TypeKind::RValue);
 
         FunctionType { arg_list, out_type }
@@ -42,20 +44,6 @@ impl Type for FunctionType {
             out_type: self.out_type.clone(),
         })
     }
-    fn value_as_str(&self) -> String {
-        let mut ans = "(".to_string();
-        for arg in &self.arg_list {
-            ans += &arg.value_as_str();
-            ans += ", ";
-        }
-
-        ans = if self.arg_list.len() == 0 {
-            "(void)".to_string()
-        } else {
-            ans[0..ans.len() - 2].to_string() + ")"
-        };
-        ans
-    }
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
         other.bin_operation_function_type(self, op)
     }
@@ -79,6 +67,9 @@ impl Type for FunctionType {
         Ok(Rc::new(FunctionType::new(args, self.out_type.get_clone())))
     }
 
+    fn get_args(&self) -> Option<Vec<Rc<Type>>> {
+        Some(self.arg_list.clone())
+    }
     fn call_by_args(&self) -> Result<Rc<Type>, SemanticErrors> {
         Ok(self.out_type.clone())
     }
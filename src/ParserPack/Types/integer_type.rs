@@ -5,17 +5,13 @@ use ParserPack::*;
 use std::rc::Rc;
 
 pub struct IntegerType {
-    pub value: Cell<i64>,
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl IntegerType {
-    pub fn new(value: i64) -> IntegerType {
+    pub fn new() -> IntegerType {
         IntegerType {
-            value: Cell::new(value),
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
         }
     }
 }
@@ -27,63 +23,22 @@ impl Type for IntegerType {
     fn as_str(&self) -> String {
         "Integer".to_string()
     }
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
-        }
-        self.value.get().to_string()
-    }
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
-        let expr = try!(parser.parse_simple_expr());
-        let value = expr.get_type().unwrap();
-        try!(self.set_value(value));
-        Ok(self.value.get().to_string())
-    }
 
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
+    fn get_variant(&self) -> ValueVariant {
+        ValueVariant::Int
     }
-
-    fn get_value(&self) -> ValueVariant {
-        ValueVariant::Int {
-            v: self.value.get(),
+    fn check_assign(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
+        if !is_mutable_kind(&self.kind.get()) || value.get_variant() != ValueVariant::Int {
+            return Err(cant_assign(&value, self.as_str()));
         }
-    }
-    fn as_integer(&self) -> Option<i64> {
-        Some(self.value.get())
-    }
-    fn as_double(&self) -> Option<f64> {
-        Some(self.value.get() as f64)
-    }
-    fn set_value(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
-        if !is_mutable_kind(&self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                value.as_str(),
-                self.as_str()
-            )));
-        }
-
-        let new_value = match value.as_integer() {
-            Some(res) => res,
-            None => {
-                return Err(self.create_err(format!(
-                    "Невозможно привести {} к {}",
-                    value.as_str(),
-                    self.as_str()
-                )))
-            }
-        };
-        self.value.set(new_value);
-        self.is_unknown.set(value.get_unknown());
         Ok("Ok".to_string())
     }
     fn set_kind(&self, kind: TypeKind) {
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
 
     fn is_enumerated(&self) -> bool {
         true
@@ -95,15 +50,11 @@ impl Type for IntegerType {
         i32::MAX
     }
     fn get_clone(&self) -> Rc<Type> {
-        Rc::new(IntegerType::new(self.value.get()))
+        Rc::new(IntegerType::new())
     }
 
-    fn unar_operation(&self, op: UnarOperation) -> Result<Rc<Type>, SemanticErrors> {
-        match op {
-            UnarOperation::Plus => Ok(Rc::new(IntegerType::new(self.value.get())) as Rc<Type>),
-            UnarOperation::Minus => Ok(Rc::new(IntegerType::new(-self.value.get())) as Rc<Type>),
-            UnarOperation::Not => Ok(Rc::new(IntegerType::new(-self.value.get())) as Rc<Type>),
-        }
+    fn unar_operation(&self, _op: UnarOperation) -> Result<Rc<Type>, SemanticErrors> {
+        Ok(Rc::new(IntegerType::new()) as Rc<Type>)
     }
 
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
@@ -112,34 +63,18 @@ impl Type for IntegerType {
 
     fn bin_operation_integer_type(
         &self,
-        other: &IntegerType,
+        _other: &IntegerType,
         op: BinOperation,
     ) -> Result<Rc<Type>, SemanticErrors> {
         match op {
-            BinOperation::Plus => {
-                Ok(Rc::new(IntegerType::new(self.value.get() + other.value.get())) as Rc<Type>)
-            }
-            BinOperation::Minus => {
-                Ok(Rc::new(IntegerType::new(other.value.get() - self.value.get())) as Rc<Type>)
-            }
-            BinOperation::Mul => {
-                Ok(Rc::new(IntegerType::new(self.value.get() * other.value.get())) as Rc<Type>)
-            }
-            BinOperation::Share => {
-                Ok(Rc::new(IntegerType::new(other.value.get() / self.value.get())) as Rc<Type>)
-            }
-            BinOperation::And => {
-                Ok(Rc::new(IntegerType::new(self.value.get() * other.value.get())) as Rc<Type>)
-            }
-            BinOperation::Or => {
-                Ok(Rc::new(IntegerType::new(other.value.get() / self.value.get())) as Rc<Type>)
-            }
-            BinOperation::OGe => Ok(create_boolean(other.value.get() >= self.value.get())),
-            BinOperation::OGt => Ok(create_boolean(other.value.get() > self.value.get())),
-            BinOperation::OEq => Ok(create_boolean(other.value.get() == self.value.get())),
-            BinOperation::OLe => Ok(create_boolean(other.value.get() <= self.value.get())),
-            BinOperation::OLt => Ok(create_boolean(other.value.get() < self.value.get())),
-            BinOperation::ONe => Ok(create_boolean(other.value.get() != self.value.get())),
+            BinOperation::OGe
+            | BinOperation::OGt
+            | BinOperation::OEq
+            | BinOperation::OLe
+            | BinOperation::OLt
+            | BinOperation::ONe => Ok(create_boolean()),
+            BinOperation::Share => Ok(Rc::new(DoubleType::new()) as Rc<Type>),
+            _ => Ok(Rc::new(IntegerType::new()) as Rc<Type>),
         }
     }
     fn bin_operation_double_type(
@@ -147,32 +82,7 @@ impl Type for IntegerType {
         other: &DoubleType,
         op: BinOperation,
     ) -> Result<Rc<Type>, SemanticErrors> {
-        match op {
-            BinOperation::Plus => Ok(Rc::new(DoubleType::new(
-                self.value.get() as f64 + other.value.get(),
-            )) as Rc<Type>),
-            BinOperation::Minus => Ok(Rc::new(DoubleType::new(
-                other.value.get() - self.value.get() as f64,
-            )) as Rc<Type>),
-            BinOperation::Mul => Ok(Rc::new(DoubleType::new(
-                self.value.get() as f64 * other.value.get(),
-            )) as Rc<Type>),
-            BinOperation::Share => Ok(Rc::new(DoubleType::new(
-                other.value.get() / self.value.get() as f64,
-            )) as Rc<Type>),
-            BinOperation::And => Ok(Rc::new(DoubleType::new(
-                self.value.get() as f64 * other.value.get(),
-            )) as Rc<Type>),
-            BinOperation::Or => Ok(Rc::new(DoubleType::new(
-                other.value.get() / self.value.get() as f64,
-            )) as Rc<Type>),
-            BinOperation::OGe => Ok(create_boolean(other.value.get() >= self.value.get() as f64)),
-            BinOperation::OGt => Ok(create_boolean(other.value.get() > self.value.get() as f64)),
-            BinOperation::OEq => Ok(create_boolean(other.value.get() == self.value.get() as f64)),
-            BinOperation::OLe => Ok(create_boolean(other.value.get() <= self.value.get() as f64)),
-            BinOperation::OLt => Ok(create_boolean(other.value.get() < self.value.get() as f64)),
-            BinOperation::ONe => Ok(create_boolean(other.value.get() != self.value.get() as f64)),
-        }
+        double_result(other.as_str(), self.as_str(), op)
     }
 
     fn cast_to(&self, other: Rc<Type>) -> Result<Rc<Type>, SemanticErrors> {
@@ -181,12 +91,11 @@ impl Type for IntegerType {
 
     fn cast_from_integer(&self, other: &IntegerType) -> Result<Rc<Type>, SemanticErrors> {
         if !kind_cast(&other.kind.get(), &self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                other.as_str(),
-                self.as_str()
-            )));
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
         }
-        Ok(Rc::new(IntegerType::new(other.value.get())))
+        Ok(Rc::new(IntegerType::new()))
     }
 }
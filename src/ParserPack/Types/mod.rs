@@ -8,6 +8,7 @@ pub mod range_type;
 pub mod record_type;
 pub mod void_type;
 pub mod support;
+pub mod const_value;
 
 pub use self::array_type::*;
 pub use self::char_type::*;
@@ -19,3 +20,4 @@ pub use self::range_type::*;
 pub use self::record_type::*;
 pub use self::void_type::*;
 pub use self::support::*;
+pub use self::const_value::*;
@@ -10,22 +10,18 @@ pub struct RangeType {
 
     value: Rc<Type>,
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl RangeType {
     pub fn new(left: i32, right: i32, value: Rc<Type>) -> Result<RangeType, SemanticErrors> {
         if right < left {
-            return Err(SemanticErrors::OtherError {
-                msg: "Неверно заданы границы range".to_string(),
-            });
+            return Err(SemanticErrors::InvalidRange);
         }
         Ok(RangeType {
             left,
             right,
             value: value.get_clone(),
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
         })
     }
 }
@@ -37,11 +33,8 @@ impl Type for RangeType {
     fn as_str(&self) -> String {
         self.left.to_string() + ".." + &self.right.to_string()
     }
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
-        }
-        self.value.value_as_str()
+    fn value_as_str(&self, value: &ConstValue) -> String {
+        self.value.value_as_str(value)
     }
 
     fn is_enumerated(&self) -> bool {
@@ -53,33 +46,22 @@ impl Type for RangeType {
     fn get_right(&self) -> i32 {
         self.right
     }
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
-    }
 
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
-        let expr = try!(parser.parse_simple_expr());
-        let value = expr.get_type().unwrap();
-        try!(self.set_value(value));
-        Ok("Ok".to_string())
+    fn get_variant(&self) -> ValueVariant {
+        self.value.get_variant()
     }
-
-    fn set_value(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
-        try!(self.value.set_value(value));
-        Ok("Ok".to_string())
+    fn check_assign(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
+        if !is_mutable_kind(&self.kind.get()) {
+            return Err(cant_assign(&value, self.as_str()));
+        }
+        self.value.check_assign(value)
     }
 
-    fn as_integer(&self) -> Option<i64> {
-        self.value.as_integer()
-    }
-    fn as_double(&self) -> Option<f64> {
-        self.value.as_double()
+    fn as_range(&self) -> Option<(i64, i64)> {
+        Some((self.left as i64, self.right as i64))
     }
-    fn as_enum(&self, name: String) -> Option<i64> {
-        self.value.as_enum(name)
+    fn as_ordinal(&self, value: &ConstValue) -> Option<i64> {
+        self.value.as_ordinal(value)
     }
 
     fn get_clone(&self) -> Rc<Type> {
@@ -88,7 +70,6 @@ impl Type for RangeType {
             right: self.right,
             value: self.value.get_clone(),
             kind: self.kind.clone(),
-            is_unknown: self.is_unknown.clone(),
         };
         Rc::new(e)
     }
@@ -96,10 +77,16 @@ impl Type for RangeType {
     fn set_kind(&self, kind: TypeKind) {
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
 
     fn unar_operation(&self, op: UnarOperation) -> Result<Rc<Type>, SemanticErrors> {
         self.value.unar_operation(op)
     }
+    fn fold_unar(&self, op: UnarOperation, value: &ConstValue) -> ConstValue {
+        self.value.fold_unar(op, value)
+    }
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
         self.value.bin_operation(other, op)
     }
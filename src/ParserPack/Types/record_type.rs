@@ -12,7 +12,6 @@ pub struct RecordType {
     field_order: Vec<String>,
 
     pub kind: Cell<TypeKind>,
-    pub is_unknown: Cell<bool>,
 }
 
 impl RecordType {
@@ -26,7 +25,6 @@ impl RecordType {
             fields: fields.clone(),
             field_order,
             kind: Cell::new(TypeKind::Var),
-            is_unknown: Cell::new(false),
         }
     }
 }
@@ -41,6 +39,23 @@ impl Type for RecordType {
         }
         size
     }
+    fn get_storage_size(&self, word_size: i64) -> i64 {
+        let mut size: i64 = 0;
+        for name in &self.field_order {
+            size += self.fields[name].get_storage_size(word_size);
+        }
+        size
+    }
+    fn get_field_offset(&self, field_name: String, word_size: i64) -> i64 {
+        let mut offset: i64 = 0;
+        for name in &self.field_order {
+            if *name == field_name {
+                break;
+            }
+            offset += self.fields[name].get_storage_size(word_size);
+        }
+        offset
+    }
     fn as_str(&self) -> String {
         let mut ans = self.name.clone() + ": ";
         for name in &self.field_order {
@@ -50,34 +65,10 @@ impl Type for RecordType {
         ans[..ans.len() - 2].to_string()
     }
 
-    fn set_unknown(&self, is_unknown: bool) {
-        self.is_unknown.set(is_unknown);
-    }
-    fn get_unknown(&self) -> bool {
-        self.is_unknown.get()
-    }
-
-    fn value_as_str(&self) -> String {
-        if self.is_unknown.get() {
-            return "Unknown".to_string();
-        }
-        let mut ans = "(".to_string();
-        for name in &self.field_order {
-            ans += &(name.clone() + ": "
-                + &self.get_by_field(name.to_string()).unwrap().value_as_str()
-                + ", ");
-        }
-        ans[..ans.len() - 2].to_string() + ")"
-    }
-
     fn get_by_field(&self, field_name: String) -> Result<Rc<Type>, SemanticErrors> {
         match self.fields.get(&field_name) {
             Some(res) => return Ok(res.clone()),
-            None => Err(self.create_err(format!(
-                "Полe {} у {} не существует",
-                field_name,
-                self.as_str()
-            ))),
+            None => Err(SemanticErrors::UnknownField { field: field_name, name: self.as_str() }),
         }
     }
 
@@ -91,7 +82,6 @@ impl Type for RecordType {
             fields: field_map,
             field_order: self.field_order.clone(),
             kind: self.kind.clone(),
-            is_unknown: self.is_unknown.clone(),
         })
     }
 
@@ -103,9 +93,13 @@ impl Type for RecordType {
         }
         self.kind.set(kind);
     }
+    fn get_kind(&self) -> TypeKind {
+        self.kind.get()
+    }
 
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
+    fn parse_init_value(&self, parser: &mut Parser) -> Result<ConstValue, CompilerErrors> {
         check_token!(parser, TokenType::TOp);
+        let mut items = vec![];
         for i in 0..self.field_order.len() {
             let name = self.field_order[i].clone();
             let t = try!(parser.tokenizer.get_and_next());
@@ -113,17 +107,14 @@ impl Type for RecordType {
                 return Err(self.err_in_parse(&t));
             }
             check_token!(parser, TokenType::TColon);
-            try!(
-                self.get_by_field(name.to_string())
-                    .unwrap()
-                    .parse_init_value(parser)
-            );
+            let value = try!(parser.parse_init_value(self.get_by_field(name.to_string()).unwrap()));
+            push_init_value(&mut items, value);
             if i < self.field_order.len() - 1 {
                 check_token!(parser, TokenType::TComma);
             }
         }
         check_token!(parser, TokenType::TCp);
-        Ok("Record".to_string())
+        Ok(ConstValue::Aggregate(items))
     }
 
     fn bin_operation(&self, other: Rc<Type>, op: BinOperation) -> Result<Rc<Type>, SemanticErrors> {
@@ -136,11 +127,10 @@ impl Type for RecordType {
 
     fn cast_from_record(&self, other: &RecordType) -> Result<Rc<Type>, SemanticErrors> {
         if !kind_cast(&other.kind.get(), &self.kind.get()) {
-            return Err(self.create_err(format!(
-                "Невозможно привести {} к {}",
-                other.as_str(),
-                self.as_str()
-            )));
+            return Err(SemanticErrors::InvalidConversion {
+                from: other.as_str(),
+                to: self.as_str(),
+            });
         }
 
         if other.name != self.name {
@@ -7,27 +7,34 @@ use TokenizerPack::token::*;
 
 pub const MAX_SIZE: i64 = 4294967296; // 2^32
 
+// Вид значений типа; сами значения хранятся отдельно, в ConstValue
+#[derive(Clone, Debug, PartialEq)]
 pub enum ValueVariant {
-    Int { v: i64 },
-    Double { v: f64 },
-    Char { v: u8 },
-    Enum { name: String, v: i64 },
+    Int,
+    Double,
+    Char,
+    Enum { name: String },
     Other,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum UnarOperation {
     Plus,
     Minus,
     Not,
+    Succ,
+    Pred,
+    Ord,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum BinOperation {
     Plus,
     Minus,
     Mul,
     Share,
+    Div,
+    Mod,
     And,
     Or,
     OGe,
@@ -48,22 +55,24 @@ pub enum TypeKind {
     Arg,
 }
 
-pub fn create_boolean(res: bool) -> Rc<Type> {
+pub fn create_boolean() -> Rc<Type> {
     let var_list = vec!["false".to_string(), "true".to_string()];
     let value_map: HashMap<String, i64> = HashMap::new();
-    match res {
-        false => Rc::new(EnumType::new(
-            var_list.clone(),
-            value_map.clone(),
-            "boolean".to_string(),
-            0,
-        )) as Rc<Type>,
-        true => Rc::new(EnumType::new(
-            var_list.clone(),
-            value_map.clone(),
-            "boolean".to_string(),
-            1,
-        )) as Rc<Type>,
+    Rc::new(EnumType::new(var_list, value_map, "boolean".to_string())) as Rc<Type>
+}
+
+pub fn cant_assign(value: &Rc<Type>, target: String) -> SemanticErrors {
+    SemanticErrors::InvalidConversion {
+        from: value.as_str(),
+        to: target,
+    }
+}
+
+// Вложенные массивы и записи лежат в памяти подряд, их значения разворачиваются
+pub fn push_init_value(items: &mut Vec<ConstValue>, value: ConstValue) {
+    match value {
+        ConstValue::Aggregate(inner) => items.extend(inner),
+        _ => items.push(value),
     }
 }
 
@@ -97,48 +106,43 @@ pub trait Type {
         0
     }
 
-    fn set_unknown(&self, _is_unknown: bool) {}
-    fn get_unknown(&self) -> bool {
-        true
-    }
-
     fn as_str(&self) -> String {
         "".to_string()
     }
-    fn value_as_str(&self) -> String {
-        "".to_string()
+    fn value_as_str(&self, value: &ConstValue) -> String {
+        value.as_str()
     }
 
     fn err_in_parse(&self, t: &Token) -> CompilerErrors {
         let x = t.coords.clone().x;
         let y = t.coords.clone().y;
-        let token = "идентификатор".to_string();
+        let len = t.coords.clone().len;
+        let token = "identifier".to_string();
         CompilerErrors::ParserError {
-            err: ParserErrors::ExpectedToken { x, y, token },
+            err: ParserErrors::ExpectedToken { x, y, len, token },
         }
     }
-    fn parse_init_value(&self, parser: &mut Parser) -> Result<String, CompilerErrors> {
-        let t = parser.tokenizer.current.clone();
-        Err(self.err_in_parse(&t))
+    fn parse_init_value(&self, parser: &mut Parser) -> Result<ConstValue, CompilerErrors> {
+        let expr = try!(parser.parse_simple_expr());
+        try_at!(self.check_assign(expr.get_type().unwrap()), &expr.get_coords());
+        Ok(expr.get_const().widen_to(&self.get_variant()))
     }
 
-    fn get_by_index(&self, _index: Rc<Type>) -> Result<Rc<Type>, SemanticErrors> {
-        Err(self.create_err(format!(
-            "Нельзя обратиться по индексу к {}",
-            self.as_str()
-        )))
+    fn get_by_index(
+        &self,
+        _index: Rc<Type>,
+        _value: &ConstValue,
+    ) -> Result<Rc<Type>, SemanticErrors> {
+        Err(SemanticErrors::NotIndexable { name: self.as_str() })
     }
     fn get_by_field(&self, _field_name: String) -> Result<Rc<Type>, SemanticErrors> {
-        Err(self.create_err(format!(
-            "Нельзя обратиться к полям {}",
-            self.as_str()
-        )))
+        Err(SemanticErrors::NoFields { name: self.as_str() })
+    }
+    fn get_args(&self) -> Option<Vec<Rc<Type>>> {
+        None
     }
     fn call_by_args(&self) -> Result<Rc<Type>, SemanticErrors> {
-        Err(self.create_err(format!(
-            "Нельзя вызвать {} как функцию",
-            self.as_str()
-        )))
+        Err(SemanticErrors::NotAFunction { name: self.as_str() })
     }
 
     fn is_enumerated(&self) -> bool {
@@ -150,39 +154,39 @@ pub trait Type {
     fn get_right(&self) -> i32 {
         0
     }
+    fn get_index_type(&self) -> Option<Rc<Type>> {
+        None
+    }
+
+    // Размер в памяти сгенерированной программы, скаляры занимают машинное слово
+    fn get_storage_size(&self, word_size: i64) -> i64 {
+        word_size
+    }
+    fn get_field_offset(&self, _field_name: String, _word_size: i64) -> i64 {
+        0
+    }
 
     fn get_clone(&self) -> Rc<Type> {
-        Rc::new(IntegerType::new(0))
-    }
-    fn create_err(&self, msg: String) -> SemanticErrors {
-        SemanticErrors::OtherError { msg }
+        Rc::new(IntegerType::new())
     }
 
     fn set_kind(&self, _kind: TypeKind) {}
-    fn get_value(&self) -> ValueVariant {
+    fn get_kind(&self) -> TypeKind {
+        TypeKind::RValue
+    }
+    fn get_variant(&self) -> ValueVariant {
         ValueVariant::Other
     }
-    fn set_value(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
-        Err(self.create_err(format!(
-            "Невозможно привести {} к {}",
-            value.as_str(),
-            self.as_str()
-        )))
+    // Проверяет, что значение типа value можно присвоить переменной этого типа
+    fn check_assign(&self, value: Rc<Type>) -> Result<String, SemanticErrors> {
+        Err(cant_assign(&value, self.as_str()))
     }
 
-    fn as_integer(&self) -> Option<i64> {
-        None
+    // Значение, которое кладется на стек во время выполнения
+    fn as_ordinal(&self, value: &ConstValue) -> Option<i64> {
+        value.as_index()
     }
-    fn as_char(&self) -> Option<u8> {
-        None
-    }
-    fn as_double(&self) -> Option<f64> {
-        None
-    }
-    fn as_enum(&self, _name: String) -> Option<i64> {
-        None
-    }
-    fn as_enum_without_name(&self) -> Option<i64> {
+    fn as_range(&self) -> Option<(i64, i64)> {
         None
     }
 
@@ -192,6 +196,9 @@ pub trait Type {
             op,
         })
     }
+    fn fold_unar(&self, op: UnarOperation, value: &ConstValue) -> ConstValue {
+        value.fold_unar(op)
+    }
     fn bin_operation(
         &self,
         other: Rc<Type>,
@@ -17,7 +17,7 @@ impl Type for VoidType {
     fn as_str(&self) -> String {
         "Void".to_string()
     }
-    fn value_as_str(&self) -> String {
+    fn value_as_str(&self, _value: &ConstValue) -> String {
         "Void".to_string()
     }
 
@@ -12,7 +12,41 @@ use std::collections::HashMap;
 
 type NodeResult = Result<Rc<Node>, CompilerErrors>;
 type TypeResult = Result<Rc<Type>, CompilerErrors>;
-type TreeResult = Result<Tree, CompilerErrors>;
+
+const DECLARATION_SYNC: [TokenType; 7] = [
+    TokenType::TBegin,
+    TokenType::TVar,
+    TokenType::TConst,
+    TokenType::TType,
+    TokenType::TFunction,
+    TokenType::TProcedure,
+    TokenType::TEof,
+];
+
+// Внутри списка объявлений ошибка пропускает только одно объявление до ;
+const DECLARATION_LIST_SYNC: [TokenType; 9] = [
+    TokenType::TSemicolom,
+    TokenType::TEnd,
+    TokenType::TBegin,
+    TokenType::TVar,
+    TokenType::TConst,
+    TokenType::TType,
+    TokenType::TFunction,
+    TokenType::TProcedure,
+    TokenType::TEof,
+];
+
+const STATEMENT_SYNC: [TokenType; 9] = [
+    TokenType::TEnd,
+    TokenType::TBegin,
+    TokenType::TVar,
+    TokenType::TConst,
+    TokenType::TType,
+    TokenType::TFunction,
+    TokenType::TProcedure,
+    TokenType::TEof,
+    TokenType::TSemicolom,
+];
 
 pub struct Parser {
     pub tokenizer: Tokenizer,
@@ -22,6 +56,9 @@ pub struct Parser {
     last_unknownenum_id: i32,
 
     in_circle: bool,
+
+    errors: Vec<(Point, CompilerErrors)>,
+    pub max_errors: usize,
 }
 
 impl Parser {
@@ -32,16 +69,94 @@ impl Parser {
             last_unknownrecord_id: 0,
             last_unknownenum_id: 0,
             in_circle: false,
+            errors: vec![],
+            max_errors: 20,
         }
     }
 
-    pub fn parse(&mut self) -> TreeResult {
-        try!(self.tokenizer.my_next());
-        Ok(Tree::new(try!(self.parse_program())))
+    pub fn parse(&mut self) -> Result<Tree, Vec<CompilerErrors>> {
+        let res = match self.tokenizer.my_next() {
+            Ok(_) => self.parse_program(),
+            Err(err) => Err(err),
+        };
+
+        match res {
+            Ok(root) => if self.errors.len() == 0 {
+                return Ok(Tree::new(root));
+            },
+            Err(err) => {
+                let coords = self.tokenizer.current.coords.clone();
+                self.errors.push((coords, err));
+            }
+        }
+
+        let mut errors: Vec<(Point, CompilerErrors)> = self.errors.drain(..).collect();
+        errors.sort_by_key(|&(ref coords, _)| (coords.y, coords.x));
+        Err(errors.into_iter().map(|(_, err)| err).collect())
+    }
+
+    // Запоминает ошибку и пропускает токены до ближайшего синхронизирующего
+    fn recover(&mut self, err: CompilerErrors, start: &Token, stop: &[TokenType]) -> Result<(), CompilerErrors> {
+        match err {
+            CompilerErrors::TokenizerError { .. }
+            | CompilerErrors::ParserError {
+                err: ParserErrors::TooManyErrors { .. },
+            } => return Err(err),
+            _ => {}
+        }
+
+        try!(self.report(&start.coords, err));
+
+        loop {
+            let t = self.tokenizer.current.clone();
+            if stop.contains(&t.token_type) {
+                if t.token_type == TokenType::TSemicolom || t.coords == start.coords {
+                    try!(self.tokenizer.my_next());
+                }
+                break;
+            }
+            try!(self.tokenizer.my_next());
+        }
+        Ok(())
+    }
+
+    // Запоминает ошибку, после которой разбор продолжается с того же места
+    fn report(&mut self, coords: &Point, err: CompilerErrors) -> Result<(), CompilerErrors> {
+        self.errors.push((coords.clone(), err));
+        if self.errors.len() >= self.max_errors {
+            return Err(CompilerErrors::from(ParserErrors::TooManyErrors {
+                count: self.errors.len(),
+            }));
+        }
+        Ok(())
+    }
+
+    // Одно объявление списка вместе с ; после него; None, если оно пропущено из-за ошибки
+    fn parse_declaration_entry(
+        &mut self,
+        t: &Token,
+        parse_entry: fn(&mut Parser, &Token) -> NodeResult,
+    ) -> Result<Option<Rc<Node>>, CompilerErrors> {
+        let scopes = self.semantic_checker.scopes_count();
+        match self.parse_declaration_item(t, parse_entry) {
+            Ok(child) => Ok(Some(child)),
+            Err(err) => {
+                self.semantic_checker.restore_scopes(scopes);
+                try!(self.recover(err, t, &DECLARATION_LIST_SYNC));
+                Ok(None)
+            }
+        }
+    }
+
+    fn parse_declaration_item(&mut self, t: &Token, parse_entry: fn(&mut Parser, &Token) -> NodeResult) -> NodeResult {
+        let child = parse_simple!(self, t, [ TokenType::TId => { parse_entry(self, t) } ], expected_token);
+        let child = try!(child);
+        check_token!(self, TokenType::TSemicolom);
+        Ok(child)
     }
 
     fn parse_program(&mut self) -> NodeResult {
-        let mut e = MainNode::new("Program".to_string());
+        let mut e = MainNode::new("Program".to_string(), self.tokenizer.current.coords.clone());
         // let child = try!( self.parse_header() );
         // e.add_child(child);
 
@@ -49,7 +164,7 @@ impl Parser {
         e.add_child(child);
         check_token!(self, TokenType::TPoint);
 
-        try!(self.semantic_checker.remove_scope());
+        try_at!(self.semantic_checker.remove_scope(), &self.tokenizer.current.coords);
         Ok(Rc::new(e))
     }
 
@@ -74,7 +189,7 @@ impl Parser {
     // }
 
     fn parse_block(&mut self) -> NodeResult {
-        let mut e = ProgramNode::new("Block".to_string());
+        let mut e = ProgramNode::new("Block".to_string(), self.tokenizer.current.coords.clone());
         let child = try!(self.parse_declarations());
         e.add_child(child);
 
@@ -86,36 +201,44 @@ impl Parser {
     }
 
     fn parse_declarations(&mut self) -> NodeResult {
-        let mut e = ProgramNode::new("Declarations".to_string());
+        let mut e = ProgramNode::new("Declarations".to_string(), self.tokenizer.current.coords.clone());
 
         loop {
             let t = self.tokenizer.current.clone();
-            break_if!(t.token_type == [TokenType::TBegin]);
-            try!(self.tokenizer.my_next());
-            let child = match parse!(self, &t, [ TokenType::TVar       => { self.parse_var_declaration_list(&t, "var_declaration".to_string()) },
-                                                 TokenType::TConst     => { self.parse_const_declaration_list(&t) },
-                                                 TokenType::TType      => { self.parse_type_declaration_list(&t) },
-                                                 TokenType::TFunction  => { self.parse_function_declaration(&t) },
-                                                 TokenType::TProcedure => { self.parse_procedure_declaration(&t) } ])
-            {
-                Some(res) => try!(res),
-                None => {
-                    return Err(self.expected_token(t.coords.x, t.coords.y, TokenType::TSemicolom));
+            break_if!(t.token_type == [TokenType::TBegin, TokenType::TEof]);
+
+            let scopes = self.semantic_checker.scopes_count();
+            match self.parse_declaration(&t) {
+                Ok(child) => e.add_child(child),
+                Err(err) => {
+                    self.semantic_checker.restore_scopes(scopes);
+                    try!(self.recover(err, &t, &DECLARATION_SYNC));
                 }
-            };
-            e.add_child(child);
+            }
         }
         Ok(Rc::new(e))
     }
 
-    fn parse_type_declaration_list(&mut self, _t: &Token) -> NodeResult {
-        let mut e = ProgramNode::new("Type declarations".to_string());
+    fn parse_declaration(&mut self, t: &Token) -> NodeResult {
+        try!(self.tokenizer.my_next());
+        match parse!(self, t, [ TokenType::TVar       => { self.parse_var_declaration_list(t, "var_declaration".to_string()) },
+                                TokenType::TConst     => { self.parse_const_declaration_list(t) },
+                                TokenType::TType      => { self.parse_type_declaration_list(t) },
+                                TokenType::TFunction  => { self.parse_function_declaration(t) },
+                                TokenType::TProcedure => { self.parse_procedure_declaration(t) } ])
+        {
+            Some(res) => res,
+            None => Err(self.expected_token(&t.coords, TokenType::TSemicolom)),
+        }
+    }
+
+    fn parse_type_declaration_list(&mut self, t: &Token) -> NodeResult {
+        let mut e = ProgramNode::new("Type declarations".to_string(), t.coords.clone());
 
         let t = try!(self.tokenizer.get_and_next());
-        let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_type_declaration(&t) } ], expected_token);
-        e.add_child(try!(child));
-
-        check_token!(self, TokenType::TSemicolom);
+        if let Some(child) = try!(self.parse_declaration_entry(&t, Parser::parse_type_declaration)) {
+            e.add_child(child);
+        }
 
         loop {
             let t = self.tokenizer.current.clone();
@@ -132,10 +255,9 @@ impl Parser {
             );
             try!(self.tokenizer.my_next());
 
-            let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_type_declaration(&t) } ], expected_token);
-            e.add_child(try!(child));
-
-            check_token!(self, TokenType::TSemicolom);
+            if let Some(child) = try!(self.parse_declaration_entry(&t, Parser::parse_type_declaration)) {
+                e.add_child(child);
+            }
         }
 
         Ok(Rc::new(e))
@@ -145,14 +267,15 @@ impl Parser {
         check_token!(self, TokenType::TEq);
         let self_type = self.parse_type(t.text.clone());
 
-        let e = Rc::new(TypedefNode::new(t.text.clone(), try!(self_type)));
-        try!(self.semantic_checker.create_typedef(e.clone(), 0));
+        let e = Rc::new(TypedefNode::new(t.text.clone(), try!(self_type), t.coords.clone()));
+        try_at!(self.semantic_checker.create_typedef(e.clone(), 0), &t.coords);
 
         Ok(e)
     }
 
     fn parse_procedure_declaration(&mut self, _t: &Token) -> NodeResult {
-        let func_name = try!(self.tokenizer.get_and_next()).text.to_string();
+        let name_t = try!(self.tokenizer.get_and_next());
+        let func_name = name_t.text.to_string();
 
         self.semantic_checker.add_scope();
         let t = self.tokenizer.current.clone();
@@ -165,7 +288,7 @@ impl Parser {
                                             } ]) {
             Some(res) => try!(res),
             None => {
-                return Err(self.expected_token(t.coords.x, t.coords.y, TokenType::TSemicolom));
+                return Err(self.expected_token(&t.coords, TokenType::TSemicolom));
             }
         };
 
@@ -182,28 +305,31 @@ impl Parser {
                     argument_list.clone(),
                     Rc::new(VoidType::new()),
                     None,
+                    name_t.coords.clone(),
                 ));
-                try!(self.semantic_checker.create_function(e.clone(), 1));
+                try_at!(self.semantic_checker.create_function(e.clone(), 1), &name_t.coords);
 
                 Some(try!(self.parse_block()))
             }
         };
         check_token!(self, TokenType::TSemicolom);
 
-        try!(self.semantic_checker.remove_scope());
+        try_at!(self.semantic_checker.remove_scope(), &self.tokenizer.current.coords);
 
         let e = Rc::new(DeclFunctionNode::new(
             func_name,
             argument_list,
             Rc::new(VoidType::new()),
             body,
+            name_t.coords.clone(),
         ));
-        try!(self.semantic_checker.create_function(e.clone(), 0));
+        try_at!(self.semantic_checker.create_function(e.clone(), 0), &name_t.coords);
         Ok(e as Rc<Node>)
     }
 
     fn parse_function_declaration(&mut self, _t: &Token) -> NodeResult {
-        let func_name = try!(self.tokenizer.get_and_next()).text.to_string();
+        let name_t = try!(self.tokenizer.get_and_next());
+        let func_name = name_t.text.to_string();
 
         self.semantic_checker.add_scope();
         let t = self.tokenizer.current.clone();
@@ -215,7 +341,7 @@ impl Parser {
         {
             Some(res) => try!(res),
             None => {
-                return Err(self.expected_token(t.coords.x, t.coords.y, TokenType::TColon));
+                return Err(self.expected_token(&t.coords, TokenType::TColon));
             }
         };
 
@@ -235,29 +361,39 @@ impl Parser {
                     argument_list.clone(),
                     out_type.clone(),
                     None,
+                    name_t.coords.clone(),
                 ));
-                try!(self.semantic_checker.create_function(e.clone(), 1));
+                try_at!(self.semantic_checker.create_function(e.clone(), 1), &name_t.coords);
+
+                let result = Rc::new(DeclVarNode::new(
+                    func_name.clone(),
+                    out_type.get_clone(),
+                    ConstValue::Undefined,
+                    name_t.coords.clone(),
+                ));
+                try_at!(self.semantic_checker.create_var(result, 0), &name_t.coords);
 
                 Some(try!(self.parse_block()))
             }
         };
         check_token!(self, TokenType::TSemicolom);
 
-        try!(self.semantic_checker.remove_scope());
+        try_at!(self.semantic_checker.remove_scope(), &self.tokenizer.current.coords);
 
         let e = Rc::new(DeclFunctionNode::new(
             func_name,
             argument_list,
             out_type,
             body,
+            name_t.coords.clone(),
         ));
-        try!(self.semantic_checker.create_function(e.clone(), 0));
+        try_at!(self.semantic_checker.create_function(e.clone(), 0), &name_t.coords);
         Ok(e as Rc<Node>)
     }
 
     fn parse_forward(&mut self) -> NodeResult {
-        try!(self.tokenizer.my_next());
-        Ok(Rc::new((ProgramNode::new("Forward".to_string()))))
+        let t = try!(self.tokenizer.get_and_next());
+        Ok(Rc::new((ProgramNode::new("Forward".to_string(), t.coords))))
     }
 
     fn parse_decl_arg_list(&mut self) -> Result<Vec<Rc<Node>>, CompilerErrors> {
@@ -268,8 +404,22 @@ impl Parser {
             break_if!(t.token_type == [TokenType::TCp]);
             try!(self.tokenizer.my_next());
 
-            let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_var_declaration(&t) } ], expected_token);
-            e.push(try!(child));
+            let child = match parse!(self, &t, [ TokenType::TId    => { self.parse_arg_declaration(&t, TypeKind::Arg) },
+                                                 TokenType::TVar   => {
+                                                    let t = try!(self.tokenizer.get_and_next());
+                                                    parse_simple!(self, &t, [ TokenType::TId => { self.parse_arg_declaration(&t, TypeKind::VarArg) } ], expected_token)
+                                                 },
+                                                 TokenType::TConst => {
+                                                    let t = try!(self.tokenizer.get_and_next());
+                                                    parse_simple!(self, &t, [ TokenType::TId => { self.parse_arg_declaration(&t, TypeKind::ConstArg) } ], expected_token)
+                                                 } ])
+            {
+                Some(res) => try!(res),
+                None => {
+                    return Err(self.expected_token(&t.coords, TokenType::TId));
+                }
+            };
+            e.push(child);
 
             let t = self.tokenizer.current.clone();
             break_if!(t.token_type == [TokenType::TCp]);
@@ -281,14 +431,33 @@ impl Parser {
         Ok(e)
     }
 
-    fn parse_const_declaration_list(&mut self, _t: &Token) -> NodeResult {
-        let mut e = ProgramNode::new("const_declaration".to_string());
+    fn parse_arg_declaration(&mut self, t: &Token, kind: TypeKind) -> NodeResult {
+        check_token!(self, TokenType::TColon);
+        let arg_type = try!(self.parse_type("None".to_string())).get_clone();
+        // массивы и записи не копируются: по значению передаются только скаляры
+        if let (TypeKind::Arg, ValueVariant::Other) = (kind, arg_type.get_variant()) {
+            let err = SemanticErrors::AggregateByValue { name: arg_type.as_str() };
+            try!(self.report(&t.coords, err.at(&t.coords)));
+        }
+        arg_type.set_kind(kind);
 
-        let t = try!(self.tokenizer.get_and_next());
-        let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_const_declaration(&t) } ], expected_token);
-        e.add_child(try!(child));
+        let e = Rc::new(DeclVarNode::new(
+            t.value.as_string(),
+            arg_type,
+            ConstValue::Undefined,
+            t.coords.clone(),
+        ));
+        try_at!(self.semantic_checker.create_var(e.clone(), 0), &t.coords);
+        Ok(e as Rc<Node>)
+    }
 
-        check_token!(self, TokenType::TSemicolom);
+    fn parse_const_declaration_list(&mut self, t: &Token) -> NodeResult {
+        let mut e = ProgramNode::new("const_declaration".to_string(), t.coords.clone());
+
+        let t = try!(self.tokenizer.get_and_next());
+        if let Some(child) = try!(self.parse_declaration_entry(&t, Parser::parse_const_declaration)) {
+            e.add_child(child);
+        }
         loop {
             let t = self.tokenizer.current.clone();
             break_if!(
@@ -304,10 +473,9 @@ impl Parser {
             );
             try!(self.tokenizer.my_next());
 
-            let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_const_declaration(&t) } ], expected_token);
-            e.add_child(try!(child));
-
-            check_token!(self, TokenType::TSemicolom);
+            if let Some(child) = try!(self.parse_declaration_entry(&t, Parser::parse_const_declaration)) {
+                e.add_child(child);
+            }
         }
 
         Ok(Rc::new(e))
@@ -317,10 +485,10 @@ impl Parser {
         let mut childs: Vec<Rc<Node>> = vec![];
 
         let t = try!(self.tokenizer.get_and_next());
-        let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_var_declaration(&t) } ], expected_token);
-        childs.push(try!(child));
-
-        check_token!(self, TokenType::TSemicolom);
+        let coords = t.coords.clone();
+        if let Some(child) = try!(self.parse_declaration_entry(&t, Parser::parse_var_declaration)) {
+            childs.push(child);
+        }
         loop {
             let t = self.tokenizer.current.clone();
             break_if!(
@@ -341,16 +509,15 @@ impl Parser {
 
             try!(self.tokenizer.my_next());
 
-            let child = parse_simple!(self, &t, [ TokenType::TId => { self.parse_var_declaration(&t) } ], expected_token);
-            childs.push(try!(child));
-
-            check_token!(self, TokenType::TSemicolom);
+            if let Some(child) = try!(self.parse_declaration_entry(&t, Parser::parse_var_declaration)) {
+                childs.push(child);
+            }
         }
 
         let e = if node_name != "var_declaration" {
-            Rc::new(RecordNode::new(node_name, childs)) as Rc<Node>
+            Rc::new(RecordNode::new(node_name, childs, coords)) as Rc<Node>
         } else {
-            Rc::new(DeclVarListNode::new(node_name, childs)) as Rc<Node>
+            Rc::new(DeclVarListNode::new(node_name, childs, coords)) as Rc<Node>
         };
 
         Ok(e)
@@ -358,15 +525,21 @@ impl Parser {
 
     fn parse_const_declaration(&mut self, t: &Token) -> NodeResult {
         check_token!(self, TokenType::TColon);
-        let var_type = try!(self.parse_type("None".to_string()));
+        let var_type = try!(self.parse_type("None".to_string())).get_clone();
 
         check_token!(self, TokenType::TEq);
-        let _res = var_type.parse_init_value(self);
+        let value = try!(self.parse_init_value(var_type.clone()));
+        var_type.set_kind(TypeKind::Const);
 
-        let e = Rc::new(DeclVarNode::new(t.value.as_string(), var_type));
+        // скалярная константа не занимает памяти, массивы и записи хранятся как переменные
+        let e = if value.is_known() {
+            Rc::new(DeclConstVarNode::new(t.value.as_string(), var_type, value, t.coords.clone())) as Rc<Node>
+        } else {
+            Rc::new(DeclVarNode::new(t.value.as_string(), var_type, value, t.coords.clone())) as Rc<Node>
+        };
 
-        try!(self.semantic_checker.create_var(e.clone(), 0));
-        Ok(e as Rc<Node>)
+        try_at!(self.semantic_checker.create_var(e.clone(), 0), &t.coords);
+        Ok(e)
     }
 
     fn parse_var_declaration(&mut self, t: &Token) -> NodeResult {
@@ -374,21 +547,28 @@ impl Parser {
         let var_type = try!(self.parse_type("None".to_string()));
 
         let curr_t = self.tokenizer.current.clone();
-        match parse!(self, &curr_t, [TokenType::TEq => { 
+        let value = match parse!(self, &curr_t, [TokenType::TEq => { 
             try!(self.tokenizer.my_next());
-            var_type.parse_init_value(self) 
+            self.parse_init_value(var_type.clone())
         } ]) {
-            Some(res) => {
-                try!(res);
-            }
-            None => {}
-        }
+            Some(res) => try!(res),
+            None => ConstValue::Undefined,
+        };
 
-        let e = Rc::new(DeclVarNode::new(t.value.as_string(), var_type));
-        try!(self.semantic_checker.create_var(e.clone(), 0));
+        let e = Rc::new(DeclVarNode::new(t.value.as_string(), var_type, value, t.coords.clone()));
+        try_at!(self.semantic_checker.create_var(e.clone(), 0), &t.coords);
         Ok(e as Rc<Node>)
     }
 
+    pub fn parse_init_value(&mut self, var_type: Rc<Type>) -> Result<ConstValue, CompilerErrors> {
+        let coords = self.tokenizer.current.coords.clone();
+        let value = try!(var_type.parse_init_value(self));
+        if !value.is_known() && var_type.get_variant() != ValueVariant::Other {
+            return Err(SemanticErrors::ExpectedConstant.at(&coords));
+        }
+        Ok(value)
+    }
+
     fn parse_type(&mut self, typename: String) -> TypeResult {
         let t = self.tokenizer.after.clone();
         match t.token_type {
@@ -402,22 +582,22 @@ impl Parser {
 
         let t = try!(self.tokenizer.get_and_next());
 
-        match parse!(self, &t, [ TokenType::TIntegerType => { Ok( Rc::new(IntegerType::new(0)) as Rc<Type> ) },             
-                                 TokenType::TDoubleType  => { Ok( Rc::new(DoubleType::new(0.0)) as Rc<Type> ) },              
-                                 TokenType::TCharType    => { Ok( Rc::new(CharType::new(0)) as Rc<Type> ) },
+        match parse!(self, &t, [ TokenType::TIntegerType => { Ok( Rc::new(IntegerType::new()) as Rc<Type> ) },             
+                                 TokenType::TDoubleType  => { Ok( Rc::new(DoubleType::new()) as Rc<Type> ) },              
+                                 TokenType::TCharType    => { Ok( Rc::new(CharType::new()) as Rc<Type> ) },
                                  TokenType::TRecord      => { self.parse_record(&t, typename) },
                                  TokenType::TOp          => { self.parse_enum(&t, typename) },
                                  TokenType::TArray       => { self.parse_array(&t) }, 
                                  TokenType::TId          => { 
-                                    let e = try!( self.semantic_checker.find_var(t.text.clone()) );
+                                    let e = try_at!( self.semantic_checker.find_var(t.text.clone()), &t.coords );
                                     match e.get_kind() {
                                         KindIdentifier::Typedef => {},
-                                        _ => {return Err( CompilerErrors::from(SemanticErrors::OtherError{msg: "Ожидался тип".to_string()}) )}
+                                        _ => {return Err( SemanticErrors::ExpectedType.at(&t.coords) )}
                                     } 
                                     Ok( e.get_type().unwrap() )
                                  } ]) {
             Some(res) => Ok(try!(res)),
-            None => Err(missing_operand(t.coords.x, t.coords.y)),
+            None => Err(missing_operand(&t.coords)),
         }
     }
 
@@ -430,27 +610,27 @@ impl Parser {
         };
 
         let mut var_list: Vec<String> = vec![];
+        let mut coords_list: Vec<Point> = vec![];
         let mut value_map: HashMap<String, i64> = HashMap::new();
 
         let t = self.tokenizer.current.clone();
         let name = t.text.clone();
         check_token!(self, TokenType::TId);
         var_list.push(t.value.as_string());
+        coords_list.push(t.coords.clone());
 
         let t = self.tokenizer.current.clone();
         match t.token_type {
             TokenType::TEq => {
                 try!(self.tokenizer.my_next());
                 let expr = try!(self.parse_simple_expr());
-                let value = expr.get_type().unwrap().as_integer();
+                let value = expr.get_const().as_integer();
                 match value {
                     Some(res) => {
                         value_map.insert(name, res);
                     }
                     None => {
-                        return Err(CompilerErrors::from(SemanticErrors::OtherError {
-                            msg: "Неверный тип выражения".to_string(),
-                        }))
+                        return Err(SemanticErrors::InvalidExpressionType.at(&expr.get_coords()))
                     }
                 }
             }
@@ -466,21 +646,20 @@ impl Parser {
             check_token!(self, TokenType::TId);
             let name = t.text.clone();
             var_list.push(name.clone());
+            coords_list.push(t.coords.clone());
 
             let t = self.tokenizer.current.clone();
             match t.token_type {
                 TokenType::TEq => {
                     try!(self.tokenizer.my_next());
                     let expr = try!(self.parse_simple_expr());
-                    let value = expr.get_type().unwrap().as_integer();
+                    let value = expr.get_const().as_integer();
                     match value {
                         Some(res) => {
                             value_map.insert(name, res);
                         }
                         None => {
-                            return Err(CompilerErrors::from(SemanticErrors::OtherError {
-                                msg: "Неверный тип выражения".to_string(),
-                            }))
+                            return Err(SemanticErrors::InvalidExpressionType.at(&expr.get_coords()))
                         }
                     }
                 }
@@ -495,79 +674,44 @@ impl Parser {
                 var_list.clone(),
                 value_map.clone(),
                 enum_name.clone(),
-                i as i64,
             )) as Rc<Type>;
-            let variant = Rc::new(DeclConstVarNode::new(name, variant_type)) as Rc<Node>;
-            try!(self.semantic_checker.create_var(variant, 0));
+            variant_type.set_kind(TypeKind::Const);
+            let value = ConstValue::Enum {
+                name: enum_name.clone(),
+                v: i as i64,
+            };
+            let variant = Rc::new(DeclConstVarNode::new(name, variant_type, value, coords_list[i].clone())) as Rc<Node>;
+            try_at!(self.semantic_checker.create_var(variant, 0), &coords_list[i]);
         }
-        let e = EnumType::new(var_list.clone(), value_map.clone(), enum_name, 0);
+        let e = EnumType::new(var_list.clone(), value_map.clone(), enum_name);
 
         Ok(Rc::new(e))
     }
 
     fn parse_range(&mut self) -> TypeResult {
-        let l = try!(self.parse_simple_expr()).get_type().unwrap();
+        let coords = self.tokenizer.current.coords.clone();
+        let l = try!(self.parse_simple_expr());
         check_token!(self, TokenType::TRange);
-        let r = try!(self.parse_simple_expr()).get_type().unwrap();
-
-        match l.as_integer() {
-            Some(l_value) => {
-                let self_type = l.get_clone();
-                match r.as_integer() {
-                    Some(r_value) => {
-                        return Ok(Rc::new(try!(RangeType::new(
-                            l_value as i32,
-                            r_value as i32,
-                            self_type
-                        ))))
-                    }
-                    None => {}
-                }
+        let r = try!(self.parse_simple_expr());
+
+        let l_type = l.get_type().unwrap();
+        let r_type = r.get_type().unwrap();
+        let same_variant = l_type.get_variant() == r_type.get_variant();
+        match (l.get_const().as_index(), r.get_const().as_index()) {
+            (Some(l_value), Some(r_value)) if same_variant => {
+                return Ok(Rc::new(try_at!(RangeType::new(
+                    l_value as i32,
+                    r_value as i32,
+                    l_type.get_clone()
+                ), &coords)))
             }
-            None => {}
-        };
-
-        match l.as_enum_without_name() {
-            Some(l_value) => {
-                let self_type = l.get_clone();
-                match r.as_enum_without_name() {
-                    Some(r_value) => {
-                        return Ok(Rc::new(try!(RangeType::new(
-                            l_value as i32,
-                            r_value as i32,
-                            self_type
-                        ))))
-                    }
-                    None => {}
-                }
-            }
-            None => {}
-        }
-
-        match l.as_char() {
-            Some(l_value) => {
-                let self_type = l.get_clone();
-                match r.as_char() {
-                    Some(r_value) => {
-                        return Ok(Rc::new(try!(RangeType::new(
-                            l_value as i32,
-                            r_value as i32,
-                            self_type
-                        ))))
-                    }
-                    None => {}
-                }
-            }
-            None => {}
+            _ => {}
         }
 
-        return Err(CompilerErrors::from(SemanticErrors::OtherError {
-            msg: format!(
-                "Нельзя создать range из {} и {}",
-                l.as_str(),
-                r.as_str()
-            ),
-        }));
+        return Err(SemanticErrors::InvalidRangeBounds {
+            left: l_type.as_str(),
+            right: r_type.as_str(),
+        }.at(&coords));
     }
 
     fn parse_record(&mut self, t: &Token, name: String) -> TypeResult {
@@ -583,7 +727,7 @@ impl Parser {
         let e = try!(self.parse_var_declaration_list(t, record_name));
 
         check_token!(self, TokenType::TEnd);
-        try!(self.semantic_checker.remove_scope());
+        try_at!(self.semantic_checker.remove_scope(), &self.tokenizer.current.coords);
 
         Ok(e.get_type().unwrap())
     }
@@ -598,48 +742,32 @@ impl Parser {
 
                 res
             }
-            _ => {
-                return Err(CompilerErrors::from(SemanticErrors::OtherError {
-                    msg: "НЕ ОШИБКА!!!".to_string(),
-                }))
-            } //ДОДЕЛАТЬ!!!!!!!!!
+            _ => return Err(self.expected_token(&t.coords, TokenType::TObr)),
         };
 
         check_token!(self, TokenType::TOf);
 
         let out_type = try!(self.parse_type("None".to_string()));
 
-        let res = try!(ArrayType::new(index_type.clone(), out_type.clone()));
+        let res = try_at!(ArrayType::new(index_type.clone(), out_type.clone()), &t.coords);
         Ok(Rc::new(res))
     }
 
     fn parse_statements(&mut self, _t: &Token) -> NodeResult {
-        let mut e = ProgramNode::new("Statements".to_string());
+        let mut e = ProgramNode::new("Statements".to_string(), self.tokenizer.current.coords.clone());
 
         loop {
             let t = self.tokenizer.current.clone();
             break_if!(t.token_type == [TokenType::TEnd, TokenType::TEof]);
 
-            let child = match parse!(self, &t, [ TokenType::TId       => { self.parse_simple_stmt(&t) },
-                                                 TokenType::TWriteln  => { self.parse_writeln() },
-                                                 TokenType::TFor      => { self.parse_for(&t) },
-                                                 TokenType::TIf       => { self.parse_if() },
-                                                 TokenType::TWhile    => { self.parse_while() },
-                                                 TokenType::TRepeat   => { self.parse_repeat() },
-                                                 TokenType::TContinue => { self.parse_break_continue("Continue".to_string()) },
-                                                 TokenType::TBreak    => { self.parse_break_continue("Break".to_string()) },
-                                                 TokenType::TBegin    => { 
-                                                    try!( self.tokenizer.my_next() ); 
-                                                    self.parse_statements(&t) 
-                                                } ]) {
-                Some(res) => try!(res),
-                None => {
-                    return Err(missing_operand(t.coords.x, t.coords.y));
+            let in_circle = self.in_circle;
+            match self.parse_statement(&t) {
+                Ok(child) => e.add_child(child),
+                Err(err) => {
+                    self.in_circle = in_circle;
+                    try!(self.recover(err, &t, &STATEMENT_SYNC));
                 }
-            };
-
-            check_token!(self, TokenType::TSemicolom);
-            e.add_child(child);
+            }
         }
 
         try!(self.tokenizer.my_next());
@@ -647,8 +775,31 @@ impl Parser {
         Ok(Rc::new(e))
     }
 
+    fn parse_statement(&mut self, t: &Token) -> NodeResult {
+        let child = match parse!(self, t, [ TokenType::TId       => { self.parse_simple_stmt(t) },
+                                            TokenType::TWriteln  => { self.parse_writeln() },
+                                            TokenType::TFor      => { self.parse_for(t) },
+                                            TokenType::TIf       => { self.parse_if() },
+                                            TokenType::TWhile    => { self.parse_while() },
+                                            TokenType::TRepeat   => { self.parse_repeat() },
+                                            TokenType::TContinue => { self.parse_break_continue("Continue".to_string()) },
+                                            TokenType::TBreak    => { self.parse_break_continue("Break".to_string()) },
+                                            TokenType::TBegin    => { 
+                                               try!( self.tokenizer.my_next() ); 
+                                               self.parse_statements(t) 
+                                           } ]) {
+            Some(res) => try!(res),
+            None => {
+                return Err(missing_operand(&t.coords));
+            }
+        };
+
+        check_token!(self, TokenType::TSemicolom);
+        Ok(child)
+    }
+
     fn parse_writeln(&mut self) -> NodeResult {
-        try!(self.tokenizer.my_next());
+        let coords = try!(self.tokenizer.get_and_next()).coords;
         check_token!(self, TokenType::TOp);
 
         let mut args: Vec<Rc<Node>> = vec![];
@@ -666,17 +817,16 @@ impl Parser {
         }
 
         check_token!(self, TokenType::TCp);
-        Ok(Rc::new(try!(WritelnNode::new(args))) as Rc<Node>)
+        Ok(Rc::new(try_at!(WritelnNode::new(args, coords.clone()), &coords)) as Rc<Node>)
     }
 
     fn parse_break_continue(&mut self, kind: String) -> NodeResult {
+        let coords = self.tokenizer.current.coords.clone();
         if self.in_circle {
             try!(self.tokenizer.my_next());
-            Ok(Rc::new(ContinueBreakNode::new(kind)) as Rc<Node>)
+            Ok(Rc::new(ContinueBreakNode::new(kind, coords)) as Rc<Node>)
         } else {
-            Err(CompilerErrors::from(SemanticErrors::OtherError {
-                msg: kind + " вызван не в цикле ",
-            }))
+            Err(SemanticErrors::NotInLoop { kind }.at(&coords))
         }
     }
 
@@ -690,30 +840,37 @@ impl Parser {
                                       TokenType::TMinAssign   => { self.parse_assign(targer) },
                                       TokenType::TMulAssign   => { self.parse_assign(targer) },
                                       TokenType::TShareAssign => { self.parse_assign(targer) },
-                                      TokenType::TSemicolom   => { Ok( targer ) }              ])
+                                      TokenType::TSemicolom   => { self.parse_call_stmt(targer) } ])
         {
             Some(node) => Ok(try!(node)),
             None => {
-                Err(self.expected_token(curr_t.coords.x, curr_t.coords.y, TokenType::TSemicolom))
+                Err(self.expected_token(&curr_t.coords, TokenType::TSemicolom))
             }
         }
     }
 
+    fn parse_call_stmt(&mut self, target: Rc<Node>) -> NodeResult {
+        if target.get_type().unwrap().as_str() != "Void" {
+            return Err(SemanticErrors::InvalidExpression.at(&target.get_coords()));
+        }
+        Ok(target)
+    }
+
     fn parse_assign(&mut self, target: Rc<Node>) -> NodeResult {
         let op = self.tokenizer.current.clone();
 
         try!(self.tokenizer.my_next());
         let object = try!(self.parse_simple_expr());
 
-        let e = try!(AssignNode::new(op, target.clone(), object.clone()));
+        let e = try_at!(AssignNode::new(op.clone(), target.clone(), object.clone()), &op.coords);
         Ok(Rc::new(e))
     }
 
     fn parse_for(&mut self, _t: &Token) -> NodeResult {
-        try!(self.tokenizer.my_next());
+        let coords = try!(self.tokenizer.get_and_next()).coords;
 
         let t = try!(self.tokenizer.get_and_next());
-        let id = parse_simple!(self, &t, [ TokenType::TId => { self.parse_simple_id(t.text.clone()) } ], expected_token);
+        let id = parse_simple!(self, &t, [ TokenType::TId => { self.parse_simple_id(t.text.clone(), t.coords.clone()) } ], expected_token);
         let id = try!(id);
 
         check_token!(self, TokenType::TAssign);
@@ -728,60 +885,63 @@ impl Parser {
 
         check_token!(self, TokenType::TDo);
 
+        let in_circle = self.in_circle;
         self.in_circle = true;
 
         let t = try!(self.tokenizer.get_and_next());
         let block = parse_simple!(self, &t, [ TokenType::TBegin => { self.parse_statements(&t) } ], expected_token);
         let block = try!(block);
 
-        self.in_circle = false;
+        self.in_circle = in_circle;
 
-        let e = try!(ForNode::new(id, start, finish, block));
+        let e = try_at!(ForNode::new(id, start, finish, block, coords.clone()), &coords);
 
         Ok(Rc::new(e))
     }
 
     fn parse_repeat(&mut self) -> NodeResult {
-        try!(self.tokenizer.my_next());
+        let coords = try!(self.tokenizer.get_and_next()).coords;
 
+        let in_circle = self.in_circle;
         self.in_circle = true;
 
         let curr_t = try!(self.tokenizer.get_and_next());
         let block = parse_simple!(self, &curr_t, [ TokenType::TBegin => { self.parse_statements(&curr_t) } ], expected_token);
         let block = try!(block);
 
-        self.in_circle = false;
+        self.in_circle = in_circle;
 
         check_token!(self, TokenType::TSemicolom);
         check_token!(self, TokenType::TUntil);
 
         let cond = try!(self.parse_simple_expr());
 
-        let e = try!(RepeatNode::new(cond, block));
+        let e = try_at!(RepeatNode::new(cond, block, coords.clone()), &coords);
         Ok(Rc::new(e))
     }
 
     fn parse_while(&mut self) -> NodeResult {
-        try!(self.tokenizer.my_next());
+        let coords = try!(self.tokenizer.get_and_next()).coords;
 
         let cond = try!(self.parse_simple_expr());
 
         check_token!(self, TokenType::TDo);
 
+        let in_circle = self.in_circle;
         self.in_circle = true;
 
         let curr_t = try!(self.tokenizer.get_and_next());
         let block = parse_simple!(self, &curr_t, [ TokenType::TBegin => { self.parse_statements(&curr_t) } ], expected_token);
         let block = try!(block);
 
-        self.in_circle = false;
+        self.in_circle = in_circle;
 
-        let e = try!(WhileNode::new(cond, block));
+        let e = try_at!(WhileNode::new(cond, block, coords.clone()), &coords);
         Ok(Rc::new(e))
     }
 
     fn parse_if(&mut self) -> NodeResult {
-        try!(self.tokenizer.my_next());
+        let coords = try!(self.tokenizer.get_and_next()).coords;
 
         let cond = try!(self.parse_simple_expr());
 
@@ -800,7 +960,7 @@ impl Parser {
             None => None,
         };
 
-        let e = try!(IfNode::new(cond, block, else_block));
+        let e = try_at!(IfNode::new(cond, block, else_block, coords.clone()), &coords);
 
         Ok(Rc::new(e))
     }
@@ -848,7 +1008,25 @@ impl Parser {
     }
 
     fn parse_factor(&mut self) -> NodeResult {
-        let t = try!(self.tokenizer.get_and_next());
+        let t = self.tokenizer.current.clone();
+        let is_operand = true_if!(
+            t.token_type
+                == [
+                    TokenType::TInt,
+                    TokenType::TDouble,
+                    TokenType::TChar,
+                    TokenType::TId,
+                    TokenType::TOp,
+                    TokenType::TPlus,
+                    TokenType::TMinus,
+                    TokenType::TNot
+                ]
+        );
+        if !is_operand {
+            return Err(missing_operand(&t.coords));
+        }
+        try!(self.tokenizer.my_next());
+
         match parse!(self, &t, [ TokenType::TInt    => { self.parse_int(&t) },
                                  TokenType::TDouble => { self.parse_double(&t) },
                                  TokenType::TChar   => { self.parse_char(&t) },
@@ -860,37 +1038,55 @@ impl Parser {
         {
             Some(res) => Ok(try!(res)),
             None => {
-                return Err(missing_operand(t.coords.x, t.coords.y));
+                return Err(missing_operand(&t.coords));
             }
         }
     }
 
     fn parse_unary(&mut self, t: &Token) -> NodeResult {
         let child = try!(self.parse_factor());
-        Ok(Rc::new(try!(UnaryOpNode::new(t.clone(), child))))
+        Ok(Rc::new(try_at!(UnaryOpNode::new(t.clone(), child), &t.coords)))
     }
 
     fn parse_double(&mut self, t: &Token) -> NodeResult {
-        let self_type = Rc::new(DoubleType::new(t.value.as_double())) as Rc<Type>;
-        Ok(Rc::new(ConstNode::new(self_type.clone())))
+        let self_type = Rc::new(DoubleType::new()) as Rc<Type>;
+        let value = ConstValue::Double(t.value.as_double());
+        Ok(Rc::new(ConstNode::new(self_type, value, t.coords.clone())))
     }
     fn parse_int(&mut self, t: &Token) -> NodeResult {
-        let self_type = Rc::new(IntegerType::new(t.value.as_int())) as Rc<Type>;
-        Ok(Rc::new(ConstNode::new(self_type.clone())))
+        let self_type = Rc::new(IntegerType::new()) as Rc<Type>;
+        let value = ConstValue::Int(t.value.as_int());
+        Ok(Rc::new(ConstNode::new(self_type, value, t.coords.clone())))
     }
     fn parse_char(&mut self, t: &Token) -> NodeResult {
         let value = t.value.as_string().as_bytes()[0];
-        let self_type = Rc::new(CharType::new(value)) as Rc<Type>;
-        Ok(Rc::new(ConstNode::new(self_type.clone())))
+        let self_type = Rc::new(CharType::new()) as Rc<Type>;
+        Ok(Rc::new(ConstNode::new(self_type, ConstValue::Char(value), t.coords.clone())))
     }
     fn parse_id(&mut self, t: &Token) -> NodeResult {
         let name = t.text.clone();
-        let mut e = try!(self.parse_simple_id(name.clone()));
+        if is_ordinal_func(&name) && self.tokenizer.current.token_type == TokenType::TOp
+            && self.semantic_checker.find_id(name.clone()).is_err()
+        {
+            return self.parse_ordinal_func(t);
+        }
+        let mut e = try!(self.parse_simple_id(name.clone(), t.coords.clone()));
+
+        let curr_t = self.tokenizer.current.clone();
+        match e.get_kind() {
+            KindIdentifier::Function | KindIdentifier::ForwardFunction => {
+                if !true_if!(curr_t.token_type == [TokenType::TOp]) {
+                    e = try!(self.create_func_call(name, vec![], t.coords.clone()));
+                }
+            }
+            _ => {}
+        }
+
         loop {
             let curr_t = self.tokenizer.current.clone();
             let name = t.text.clone();
 
-            e = match parse!(self, &curr_t, [ TokenType::TOp    => { self.parse_func_call(name) },
+            e = match parse!(self, &curr_t, [ TokenType::TOp    => { self.parse_func_call(name, t.coords.clone()) },
                                               TokenType::TObr   => { self.parse_array_element( e.clone() ) },
                                               TokenType::TPoint => { self.parse_record_field( e.clone() ) }  ])
             {
@@ -899,17 +1095,23 @@ impl Parser {
             }
         }
     }
-    fn parse_simple_id(&mut self, name: String) -> NodeResult {
-        let e = try!(self.semantic_checker.find_id(name));
+    fn parse_ordinal_func(&mut self, t: &Token) -> NodeResult {
+        check_token!(self, TokenType::TOp);
+        let arg = try!(self.parse_simple_expr());
+        check_token!(self, TokenType::TCp);
+
+        Ok(Rc::new(try_at!(UnaryOpNode::new(t.clone(), arg), &t.coords)))
+    }
+
+    fn parse_simple_id(&mut self, name: String, coords: Point) -> NodeResult {
+        let e = try_at!(self.semantic_checker.find_id(name), &coords);
         match e.get_kind() {
             KindIdentifier::Typedef | KindIdentifier::Other => {
-                Err(CompilerErrors::from(SemanticErrors::OtherError {
-                    msg: "Недопустимое выражение".to_string(),
-                }))
+                Err(SemanticErrors::InvalidExpression.at(&coords))
             }
             _ => {
                 let rc_node = e.clone();
-                Ok(Rc::new((IdNode::new(rc_node as Rc<Node>))))
+                Ok(Rc::new((IdNode::new(rc_node as Rc<Node>, coords))))
             }
         }
     }
@@ -919,43 +1121,49 @@ impl Parser {
         let t = try!(self.tokenizer.get_and_next());
         let field_name = t.text.clone();
 
-        let e = try!(RecordFieldNode::new(parent.clone(), field_name));
+        let e = try_at!(RecordFieldNode::new(parent.clone(), field_name, t.coords.clone()), &t.coords);
         Ok(Rc::new(e))
     }
 
     fn parse_array_element(&mut self, parent: Rc<Node>) -> NodeResult {
-        try!(self.tokenizer.my_next());
+        let coords = try!(self.tokenizer.get_and_next()).coords;
 
         let index = try!(self.parse_simple_expr());
         check_token!(self, TokenType::TCbr);
 
-        let e = try!(ArrayElementNode::new(parent.clone(), index.clone()));
+        let e = try_at!(ArrayElementNode::new(parent.clone(), index.clone(), coords.clone()), &coords);
         Ok(Rc::new(e))
     }
 
-    fn parse_func_call(&mut self, name: String) -> NodeResult {
+    fn parse_func_call(&mut self, name: String, coords: Point) -> NodeResult {
         let t = self.tokenizer.after.clone();
-        let ttype = match t.token_type {
+        let args = match t.token_type {
             TokenType::TCp => {
                 try!(self.tokenizer.my_next());
-                Rc::new(FunctionType::new(vec![], Rc::new(VoidType::new())))
+                vec![]
             }
             _ => try!(self.parse_arg_list()),
         };
         try!(self.tokenizer.my_next());
 
-        let func = try!(self.semantic_checker.find_override(name.clone(), ttype));
-        let e = try!(FunctionCallNode::new(name, func));
+        self.create_func_call(name, args, coords)
+    }
+
+    fn create_func_call(&mut self, name: String, args: Vec<Rc<Node>>, coords: Point) -> NodeResult {
+        let arg_types = args.iter().map(|arg| arg_type(arg)).collect();
+        let ttype = Rc::new(FunctionType::new(arg_types, Rc::new(VoidType::new())));
+
+        let func = try_at!(self.semantic_checker.find_override(name.clone(), ttype), &coords);
+        let e = try_at!(FunctionCallNode::new(name, func, args, coords.clone()), &coords);
         Ok(Rc::new(e))
     }
 
-    fn parse_arg_list(&mut self) -> TypeResult {
-        let mut args: Vec<Rc<Type>> = vec![];
+    fn parse_arg_list(&mut self) -> Result<Vec<Rc<Node>>, CompilerErrors> {
+        let mut args: Vec<Rc<Node>> = vec![];
 
         let t = try!(self.tokenizer.get_and_next());
         let child = parse_simple!(self, &t, [TokenType::TOp => { self.parse_simple_expr() } ], expected_token);
-
-        args.push(try!(child).get_type().unwrap());
+        args.push(try!(child));
 
         loop {
             let t = self.tokenizer.current.clone();
@@ -963,12 +1171,10 @@ impl Parser {
             try!(self.tokenizer.my_next());
 
             let child = parse_simple!(self, &t, [TokenType::TComma => { self.parse_simple_expr() } ], expected_token);
-            args.push(try!(child).get_type().unwrap());
+            args.push(try!(child));
         }
 
-        let e = FunctionType::new(args, Rc::new(VoidType::new()));
-
-        Ok(Rc::new((e)))
+        Ok(args)
     }
 
     fn parse_op_in_expr(&mut self, _t: &Token) -> NodeResult {
@@ -977,7 +1183,7 @@ impl Parser {
         return e;
     }
 
-    pub fn expected_token(&self, x: i32, y: i32, token_type: TokenType) -> CompilerErrors {
+    pub fn expected_token(&self, coords: &Point, token_type: TokenType) -> CompilerErrors {
         let token = match token_type {
             TokenType::TSemicolom => ";".to_string(),
             TokenType::TColon => ":".to_string(),
@@ -994,13 +1200,25 @@ impl Parser {
             TokenType::TThen => "then".to_string(),
             TokenType::TDo => "do".to_string(),
             TokenType::TOf => "of".to_string(),
-            TokenType::TId => "идентификатор".to_string(),
-            TokenType::TInt => "целое число".to_string(),
-            TokenType::TDouble => "вещественное число".to_string(),
-            TokenType::TChar => "символ".to_string(),
+            TokenType::TId => "identifier".to_string(),
+            TokenType::TInt => "integer".to_string(),
+            TokenType::TDouble => "real".to_string(),
+            TokenType::TChar => "character".to_string(),
             _ => "".to_string(),
         };
-        let err = ParserErrors::ExpectedToken { x, y, token };
+        let err = ParserErrors::ExpectedToken {
+            x: coords.x,
+            y: coords.y,
+            len: coords.len,
+            token,
+        };
         CompilerErrors::ParserError { err }
     }
 }
+
+fn is_ordinal_func(name: &str) -> bool {
+    match name {
+        "succ" | "pred" | "ord" => true,
+        _ => false,
+    }
+}
@@ -1,7 +1,12 @@
 use support::*;
+use TokenizerPack::support::Point;
 
-pub fn missing_operand(x: i32, y: i32) -> CompilerErrors {
-    let err = ParserErrors::MissingOperand { x, y };
+pub fn missing_operand(coords: &Point) -> CompilerErrors {
+    let err = ParserErrors::MissingOperand {
+        x: coords.x,
+        y: coords.y,
+        len: coords.len,
+    };
     CompilerErrors::ParserError { err }
 }
 
@@ -19,7 +24,7 @@ macro_rules! parse_bin {
             let right = try!( $self.$next_func() );
 
             let old_e = e;
-            let new_e = try!(BinNode::new(t, old_e, right));
+            let new_e = try_at!(BinNode::new(t.clone(), old_e, right), &t.coords);
 
             e = Rc::new( new_e );
             t = $self.tokenizer.current.clone();
@@ -29,6 +34,15 @@ macro_rules! parse_bin {
     })
 }
 
+macro_rules! try_at {
+    ($res: expr, $coords: expr) => ({
+        match $res {
+            Ok(val) => val,
+            Err(err) => { return Err(err.at($coords)); }
+        }
+    })
+}
+
 macro_rules! parse {
     ($self:ident, $curr_t: expr, [$($var: path => $next_func:block),*]) => ({
         let curr_t = $curr_t;
@@ -47,7 +61,7 @@ macro_rules! parse_simple {
         let curr_t = $curr_t;
         match parse!($self, curr_t, [$var => $next_func]) {
             Some(res) => res,
-            None => { return Err($self.$experted_func(curr_t.coords.x, curr_t.coords.y, $var)); }
+            None => { return Err($self.$experted_func(&curr_t.coords, $var)); }
         }
     })
 }
@@ -69,12 +83,13 @@ macro_rules! true_if {
 
 macro_rules! check_token {
     ($self:ident, $var: path $(,$opt: path)*) => ({
+        // Чужой токен не снимается: восстановление после ошибки начнется с него
         let t = $self.tokenizer.current.clone();
-        try!( $self.tokenizer.my_next() );
         match t.token_type {
             $var => {},
             $($opt => {},)*
-            _ => { return Err($self.expected_token(t.coords.x, t.coords.y, $var)); }
+            _ => { return Err($self.expected_token(&t.coords, $var)); }
         }
+        try!( $self.tokenizer.my_next() );
     })
 }
@@ -12,6 +12,11 @@ impl Tree {
         Tree { root }
     }
 
+    // Сворачивает константные выражения, подставляет константы и убирает мертвые ветви if
+    pub fn fold_constants(&self) -> Tree {
+        Tree::new(fold_node(&self.root))
+    }
+
     pub fn generate(&self, generator: &mut Generator) {
         self.root.generate(generator);
     }
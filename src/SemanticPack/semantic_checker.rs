@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use support::SemanticErrors;
 use ParserPack::*;
+use TokenizerPack::support::Point;
 
 type Overrides = HashMap<String, Rc<Node>>;
 
@@ -35,18 +36,20 @@ impl Scope {
 
     pub fn add_var(&mut self, id: Rc<Node>) -> Result<String, SemanticErrors> {
         match self.vars.get(&id.get_name()) {
-            Some(_res) => {
+            Some(res) => {
                 return Err(SemanticErrors::DuplicateIdentifier {
                     name: id.get_name(),
+                    prev: res.get_coords(),
                 })
             }
             None => {}
         };
 
         match self.functions.get(&id.get_name()) {
-            Some(_res) => {
+            Some(res) => {
                 return Err(SemanticErrors::DuplicateIdentifier {
                     name: id.get_name(),
+                    prev: res.values().next().unwrap().get_coords(),
                 })
             }
             None => {}
@@ -62,7 +65,12 @@ impl Scope {
         let name = id.get_name();
 
         match self.vars.get(&name) {
-            Some(_res) => return Err(SemanticErrors::DuplicateIdentifier { name: name.clone() }),
+            Some(res) => {
+                return Err(SemanticErrors::DuplicateIdentifier {
+                    name: name.clone(),
+                    prev: res.get_coords(),
+                })
+            }
             None => {}
         };
 
@@ -78,18 +86,12 @@ impl Scope {
                                 self.count_forward -= 1;
                             }
                             _ => {
-                                return Err(SemanticErrors::OtherError {
-                                    msg: "Ошибка при перегрузке функции"
-                                        .to_string(),
-                                })
+                                return Err(SemanticErrors::InvalidOverride)
                             }
                         };
                     }
                     _ => {
-                        return Err(SemanticErrors::OtherError {
-                            msg: "Ошибка при перегрузке функции"
-                                .to_string(),
-                        })
+                        return Err(SemanticErrors::InvalidOverride)
                     }
                 }
             }
@@ -129,31 +131,15 @@ impl Scope {
 fn create_system_scope() -> Box<Scope> {
     let mut scope = Box::new(Scope::new());
 
-    let var_list = vec!["false".to_string(), "true".to_string()];
-    let value_map: HashMap<String, i64> = HashMap::new();
-
-    let tboolean = Rc::new(EnumType::new(
-        var_list.clone(),
-        value_map.clone(),
-        "boolean".to_string(),
-        0,
-    )) as Rc<Type>;
-    let tfalse = Rc::new(EnumType::new(
-        var_list.clone(),
-        value_map.clone(),
-        "boolean".to_string(),
-        0,
-    )) as Rc<Type>;
-    let ttrue = Rc::new(EnumType::new(
-        var_list.clone(),
-        value_map.clone(),
-        "boolean".to_string(),
-        1,
-    )) as Rc<Type>;
-
-    let nboolean = Rc::new(TypedefNode::new("boolean".to_string(), tboolean)) as Rc<Node>;
-    let nfalse = Rc::new(DeclConstVarNode::new("false".to_string(), tfalse)) as Rc<Node>;
-    let ntrue = Rc::new(DeclConstVarNode::new("true".to_string(), ttrue)) as Rc<Node>;
+    let tboolean = create_boolean();
+    let tfalse = create_boolean();
+    let ttrue = create_boolean();
+    tfalse.set_kind(TypeKind::Const);
+    ttrue.set_kind(TypeKind::Const);
+
+    let nboolean = Rc::new(TypedefNode::new("boolean".to_string(), tboolean, Point { x: 0, y: 0, len: 0 })) as Rc<Node>;
+    let nfalse = Rc::new(DeclConstVarNode::new("false".to_string(), tfalse, ConstValue::boolean(false), Point { x: 0, y: 0, len: 0 })) as Rc<Node>;
+    let ntrue = Rc::new(DeclConstVarNode::new("true".to_string(), ttrue, ConstValue::boolean(true), Point { x: 0, y: 0, len: 0 })) as Rc<Node>;
 
     scope.add_id(nboolean).unwrap();
     scope.add_id(ntrue).unwrap();
@@ -177,6 +163,12 @@ impl SemanticChecker {
     pub fn add_scope(&mut self) {
         self.scopes.push(Box::new(Scope::new()));
     }
+    pub fn scopes_count(&self) -> usize {
+        self.scopes.len()
+    }
+    pub fn restore_scopes(&mut self, count: usize) {
+        self.scopes.truncate(count);
+    }
     pub fn remove_scope(&mut self) -> Result<String, SemanticErrors> {
         let scope = self.scopes.pop().unwrap();
 
@@ -311,14 +303,83 @@ impl SemanticChecker {
             None => return Err(SemanticErrors::NotAFunction { name }),
         };
 
-        for (_name, over) in overrides {
+        let args = func.get_args().unwrap();
+        let mut candidates: Vec<(Rc<Type>, Vec<i32>)> = vec![];
+        let mut last_err = None;
+        for (_sign, over) in &overrides {
             let ttype = over.get_type().unwrap();
-            match func.cast_to(ttype) {
-                Ok(res) => return Ok(res.clone()),
-                Err(err) => return Err(err),
+            match override_ranks(&args, &ttype.get_args().unwrap()) {
+                Ok(ranks) => candidates.push((ttype, ranks)),
+                Err(err) => last_err = Some(err),
+            };
+        }
+
+        if candidates.len() == 0 {
+            return match (overrides.len(), last_err) {
+                (1, Some(err)) => Err(err),
+                _ => Err(SemanticErrors::UnknownOverride { name, sign }),
             };
         }
 
-        Err(SemanticErrors::UnknownOverride { name, sign })
+        let best: Vec<&(Rc<Type>, Vec<i32>)> = candidates
+            .iter()
+            .filter(|a| candidates.iter().all(|b| is_better_or_equal(&a.1, &b.1)))
+            .collect();
+
+        if best.len() == 1 {
+            return Ok(best[0].0.clone());
+        }
+
+        let mut signs: Vec<String> = candidates.iter().map(|c| c.0.as_str()).collect();
+        signs.sort();
+        Err(SemanticErrors::AmbiguousOverride { name, signs })
     }
 }
+
+// 0 - точное совпадение, 1 - поддиапазон к базовому типу, 2 - расширение Integer до Double
+fn arg_rank(arg: &Rc<Type>, param: &Rc<Type>) -> Result<i32, SemanticErrors> {
+    let exact = arg.as_str() == param.as_str();
+    match param.get_kind() {
+        TypeKind::VarArg => {
+            if !kind_cast(&arg.get_kind(), &TypeKind::VarArg) {
+                return Err(SemanticErrors::ExpectedVariable);
+            }
+            if !exact {
+                return Err(SemanticErrors::CastError {
+                    this: arg.as_str(),
+                    other: "var ".to_string() + &param.as_str(),
+                });
+            }
+            Ok(0)
+        }
+        _ => {
+            try!(arg.cast_to(param.clone()));
+            if exact {
+                Ok(0)
+            } else if arg.get_variant() == param.get_variant() {
+                Ok(1)
+            } else {
+                Ok(2)
+            }
+        }
+    }
+}
+
+fn override_ranks(args: &Vec<Rc<Type>>, params: &Vec<Rc<Type>>) -> Result<Vec<i32>, SemanticErrors> {
+    if args.len() != params.len() {
+        return Err(SemanticErrors::ArgCountMismatch {
+            expected: params.len(),
+            passed: args.len(),
+        });
+    }
+
+    let mut ranks = vec![];
+    for i in 0..args.len() {
+        ranks.push(try!(arg_rank(&args[i], &params[i])));
+    }
+    Ok(ranks)
+}
+
+fn is_better_or_equal(a: &Vec<i32>, b: &Vec<i32>) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| x <= y)
+}
@@ -1,28 +1,44 @@
 use std::fs::File;
+use std::io;
 use std::io::prelude::*;
-use std::io::BufReader;
 use std::collections::HashMap;
 
+pub type TransitionTable = HashMap<String, Vec<String>>;
+
 pub struct Parser;
 
 impl Parser {
-    pub fn load_form_csv(file_name: &str) -> HashMap<String, Vec<String>> {
-        let file = File::open(file_name).unwrap();
-        let mut reader = BufReader::new(file);
+    pub fn load_form_csv(file_name: &str) -> io::Result<TransitionTable> {
+        let mut text = String::new();
+        try!(try!(File::open(file_name)).read_to_string(&mut text));
+
+        Parser::load_from_str(&text)
+    }
 
-        let mut t_table: HashMap<String, Vec<String>> = HashMap::new();
+    pub fn load_from_str(text: &str) -> io::Result<TransitionTable> {
+        let mut t_table: TransitionTable = HashMap::new();
+        let mut lines = text.lines();
 
-        let mut line = String::new();
-        reader.read_line(&mut line).unwrap();
-        let cols: Vec<&str> = line.trim().split("|").collect();
+        // первая ячейка заголовка пуста, так что первый символ - разделитель
+        let header = lines.next().unwrap_or("");
+        let separator = match header.chars().next() {
+            Some(ch) => ch,
+            None => return Err(invalid_table("пустой заголовок")),
+        };
+        let cols: Vec<&str> = header.trim().split(separator).collect();
+        for range in &cols[1..] {
+            if !is_valid_range(range) {
+                return Err(invalid_table("неверный диапазон символов в заголовке"));
+            }
+        }
 
-        let mut len = 1;
-        'gl: while len != 0 {
-            let mut row = String::new();
-            len = reader.read_line(&mut row).unwrap();
+        'gl: for row in lines {
             let mut new_state: Vec<String> = vec!["end".to_string(); 255];
 
-            let cells: Vec<&str> = row.trim().split("|").collect();
+            let cells: Vec<&str> = row.trim().split(separator).collect();
+            if cells.len() > cols.len() {
+                return Err(invalid_table("строка шире заголовка"));
+            }
             for i in 1..cells.len() {
                 let mut range = cols[i];
                 if cells[0] == "literal" {
@@ -50,6 +66,33 @@ impl Parser {
             t_table.insert(cells[0].to_string(), new_state);
         }
 
-        t_table
+        if !t_table.contains_key("start") {
+            return Err(invalid_table("нет состояния start"));
+        }
+        // end и none - служебные: конец лексемы и ошибка
+        for states in t_table.values() {
+            for state in states {
+                if state != "end" && state != "none" && !t_table.contains_key(state) {
+                    return Err(invalid_table("переход в неизвестное состояние"));
+                }
+            }
+        }
+
+        Ok(t_table)
+    }
+}
+
+fn invalid_table(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
+// Один символ или диапазоны вида a-z через пробел
+fn is_valid_range(range: &str) -> bool {
+    if range.len() == 1 {
+        return range.as_bytes()[0] < 255;
     }
+    range.split(" ").all(|segment| {
+        let bytes = segment.as_bytes();
+        bytes.len() == 3 && bytes[1] == b'-' && bytes[0] <= bytes[2] && bytes[2] < 255
+    })
 }
@@ -1,6 +1,7 @@
 use std::fs::File;
+use std::io;
 use std::io::prelude::*;
-use std::io::BufReader;
+use std::io::{BufReader, Cursor};
 use std::i32;
 
 pub enum FatChar {
@@ -9,26 +10,41 @@ pub enum FatChar {
 }
 
 pub struct FileReader {
-    curr_line: String,
+    curr_line: Vec<char>,
     buffer: Vec<char>,
     pointer: i32,
 
-    reader: BufReader<File>,
+    reader: Box<BufRead>,
 }
 
 impl FileReader {
-    pub fn new(file_name: &str) -> FileReader {
-        let file = File::open(file_name).unwrap();
-        let mut reader = BufReader::new(file);
+    pub fn new(file_name: &str) -> io::Result<FileReader> {
+        let file = try!(File::open(file_name));
+        Ok(FileReader::from_reader(BufReader::new(file)))
+    }
 
-        let mut line = String::new();
-        reader.read_line(&mut line).unwrap();
+    pub fn from_str(source: &str) -> FileReader {
+        FileReader::from_reader(Cursor::new(source.to_string().into_bytes()))
+    }
 
-        FileReader {
-            reader: reader,
-            curr_line: line,
+    pub fn from_reader<R: BufRead + 'static>(reader: R) -> FileReader {
+        let mut res = FileReader {
+            reader: Box::new(reader),
+            curr_line: vec![],
             buffer: vec![],
             pointer: 0,
+        };
+        res.read_line();
+
+        res
+    }
+
+    fn read_line(&mut self) {
+        let mut line = String::new();
+        // ошибка чтения (например, не UTF-8) считается концом файла
+        match self.reader.read_line(&mut line) {
+            Ok(_) => self.curr_line = line.chars().collect(),
+            Err(_) => self.curr_line.clear(),
         }
     }
 
@@ -41,15 +57,10 @@ impl FileReader {
             return FatChar::Eof;
         }
 
-        let chs: Vec<char> = self.curr_line.chars().collect();
-        let ch = chs[self.pointer as usize];
+        let ch = self.curr_line[self.pointer as usize];
         if self.pointer == (self.curr_line.len() - 1) as i32 {
             self.pointer = 0;
-            self.curr_line.clear();
-
-            let mut line = String::new();
-            self.reader.read_line(&mut line).unwrap();
-            self.curr_line = line;
+            self.read_line();
         } else {
             self.pointer += 1;
         }
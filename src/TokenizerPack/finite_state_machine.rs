@@ -1,5 +1,7 @@
 use std::collections::HashMap;
-use TokenizerPack::csv_parser::Parser;
+use TokenizerPack::csv_parser::*;
+
+static TRANSITION_TABLE: &'static str = include_str!("../../TransitionTable.csv");
 
 pub struct FSMachine {
     state: String,
@@ -9,9 +11,10 @@ pub struct FSMachine {
 
 impl FSMachine {
     pub fn new() -> FSMachine {
-        let file_csv = "TransitionTable.csv";
-        let t_table = Parser::load_form_csv(file_csv);
+        FSMachine::from_table(Parser::load_from_str(TRANSITION_TABLE).unwrap())
+    }
 
+    pub fn from_table(t_table: TransitionTable) -> FSMachine {
         FSMachine {
             state: "start".to_string(),
             t_table: t_table,
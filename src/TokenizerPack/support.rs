@@ -3,7 +3,7 @@ use std::i64;
 use std::collections::HashMap;
 use std::fmt;
 
-#[derive(Clone, name_by_field)]
+#[derive(Clone, PartialEq, name_by_field)]
 pub enum TokenType {
     TColon,
     TDouble,
@@ -242,10 +242,11 @@ pub static KEY_WORDS: &'static [&'static str] = &[
     "writeln",
 ];
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
+    pub len: i32,
 }
 
 #[derive(Clone)]
@@ -19,7 +19,7 @@ impl Token {
 
             value: Value::Str { v: "0".to_string() },
             text: "0".to_string(),
-            coords: Point { x: 0, y: 0 },
+            coords: Point { x: 0, y: 0, len: 0 },
         }
     }
 
@@ -177,11 +177,12 @@ impl Token {
             }
         }
 
+        let len = text.chars().count() as i32;
         Ok(Token {
             token_type: (*TYPE_BY_STATE.get(&token_type_str).unwrap()).clone(),
             value: value,
             text: text,
-            coords: coords,
+            coords: Point { len, ..coords },
         })
     }
 }
@@ -1,6 +1,9 @@
 use std::i32;
+use std::io;
+use std::io::BufRead;
 use TokenizerPack::file_reader::*;
 use TokenizerPack::finite_state_machine::FSMachine;
+use TokenizerPack::csv_parser::TransitionTable;
 use TokenizerPack::support::*;
 use TokenizerPack::token::Token;
 use support::*;
@@ -25,13 +28,24 @@ pub enum ErrorState {
 }
 
 impl Tokenizer {
-    pub fn new(file_name: String) -> Tokenizer {
-        let reader = FileReader::new(&file_name);
+    pub fn new(file_name: String) -> io::Result<Tokenizer> {
+        let reader = try!(FileReader::new(&file_name));
+        Ok(Tokenizer::from_file_reader(reader))
+    }
+
+    pub fn from_str(source: &str) -> Tokenizer {
+        Tokenizer::from_file_reader(FileReader::from_str(source))
+    }
+
+    pub fn from_reader<R: BufRead + 'static>(reader: R) -> Tokenizer {
+        Tokenizer::from_file_reader(FileReader::from_reader(reader))
+    }
 
+    fn from_file_reader(reader: FileReader) -> Tokenizer {
         Tokenizer {
             machine: FSMachine::new(),
 
-            pointer: Point { x: 0, y: 1 },
+            pointer: Point { x: 0, y: 1, len: 0 },
 
             line_comment: false,
             depth_comment: 0,
@@ -42,6 +56,10 @@ impl Tokenizer {
         }
     }
 
+    pub fn set_transition_table(&mut self, t_table: TransitionTable) {
+        self.machine = FSMachine::from_table(t_table);
+    }
+
     fn check_comments(&mut self, ch: char) -> bool {
         if self.line_comment {
             if ch == '\n' {
@@ -85,6 +103,7 @@ impl Tokenizer {
         while state != "end" {
             match self.reader.next_char() {
                 FatChar::Char { ch } => {
+                    let prev_pointer = self.pointer.clone();
                     self.move_pointer(ch);
                     if state == "start" {
                         token_coords = self.pointer.clone();
@@ -105,8 +124,8 @@ impl Tokenizer {
                     if state == "none" {
                         let error = ErrorState::Critical {
                             err: TokenizerErrors::SimpleError {
-                                x: self.pointer.y,
-                                y: self.pointer.x,
+                                x: self.pointer.x,
+                                y: self.pointer.y,
                             },
                         };
                         return Err(error);
@@ -123,6 +142,7 @@ impl Tokenizer {
                                     self.pointer = Point {
                                         x: self.pointer.x - 2,
                                         y: self.pointer.y,
+                                        len: 0,
                                     };
 
                                     self.machine.init();
@@ -139,10 +159,7 @@ impl Tokenizer {
                         }
                     } else if text != "" {
                         self.reader.push_back(ch);
-                        self.pointer = Point {
-                            x: self.pointer.x - 1,
-                            y: self.pointer.y,
-                        };
+                        self.pointer = prev_pointer;
                         match Token::new(token_type_str, text, token_coords) {
                             Ok(token) => return Ok(token),
                             Err(err) => {
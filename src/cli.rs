@@ -0,0 +1,282 @@
+use PasC::DiagnosticPack::*;
+use PasC::GeneratorPack::{Checks, Target};
+
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_IO: i32 = 3;
+pub const EXIT_LEXICAL: i32 = 4;
+pub const EXIT_SYNTAX: i32 = 5;
+pub const EXIT_SEMANTIC: i32 = 6;
+pub const EXIT_TOOL: i32 = 7;
+
+// Опции и ключи каталога с их описаниями
+const HELP: [(&'static str, &'static str); 18] = [
+    ("-h, --help", "help.help"),
+    ("-l", "help.tokens"),
+    ("-p", "help.ast"),
+    ("-g", "help.asm"),
+    ("--emit tokens|ast|ir|asm|obj|exe", "help.emit"),
+    ("-o path", "help.output"),
+    ("-f", "help.in_file"),
+    ("-e count", "help.max_errors"),
+    ("--lang en|ru", "help.lang"),
+    ("--error-format=human|json", "help.error_format"),
+    ("--lexer-table file", "help.lexer_table"),
+    ("--assembler cmd", "help.assembler"),
+    ("--linker cmd", "help.linker"),
+    ("--target elf32-linux|elf64-linux|win32|macho32", "help.target"),
+    ("--short-circuit", "help.short_circuit"),
+    ("--checks bounds,range,overflow", "help.checks"),
+    ("-", "help.stdin"),
+    ("", "help.exit_codes"),
+];
+
+pub fn usage(lang: Lang) -> String {
+    let mut lines = vec![message("cli.usage", lang)];
+    for &(option, key) in HELP.iter() {
+        if option.is_empty() {
+            lines.push(message(key, lang));
+        } else {
+            lines.push(format!("{} -> {}", option, message(key, lang)));
+        }
+    }
+    lines.join("\n")
+}
+
+// Язык сообщений нужен раньше, чем разобраны остальные опции
+pub fn lang_from_args(args: &[String]) -> Lang {
+    let mut res = Lang::from_env();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let val = if arg == "--lang" {
+            iter.next().cloned()
+        } else if arg.starts_with("--lang=") {
+            Some(arg["--lang=".len()..].to_string())
+        } else {
+            None
+        };
+        if let Some(lang) = val.and_then(|val| Lang::from_name(&val)) {
+            res = lang;
+        }
+    }
+    res
+}
+
+pub struct UsageError {
+    pub key: &'static str,
+    pub args: Vec<String>,
+}
+
+impl UsageError {
+    pub fn new(key: &'static str) -> UsageError {
+        UsageError { key, args: vec![] }
+    }
+
+    pub fn with_arg(key: &'static str, arg: String) -> UsageError {
+        UsageError { key, args: vec![arg] }
+    }
+
+    pub fn message(&self, lang: Lang) -> String {
+        format_message(self.key, &self.args, lang)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Emit {
+    Tokens,
+    Ast,
+    Ir,
+    Asm,
+    Obj,
+    Exe,
+}
+
+impl Emit {
+    pub fn from_name(name: &str) -> Option<Emit> {
+        match name {
+            "tokens" => Some(Emit::Tokens),
+            "ast" => Some(Emit::Ast),
+            "ir" => Some(Emit::Ir),
+            "asm" => Some(Emit::Asm),
+            "obj" => Some(Emit::Obj),
+            "exe" => Some(Emit::Exe),
+            _ => None,
+        }
+    }
+}
+
+pub struct Args {
+    pub inputs: Vec<String>,
+    pub emit: Emit,
+    pub output: Option<String>,
+    pub in_file: bool,
+    pub max_errors: usize,
+    pub lang: Lang,
+    pub error_format: ErrorFormat,
+    pub lexer_table: Option<String>,
+    pub assembler: Option<String>,
+    pub linker: Option<String>,
+    pub target: Target,
+    pub short_circuit: bool,
+    pub checks: Checks,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse(args: Vec<String>) -> Result<Args, UsageError> {
+        let mut res = Args {
+            inputs: vec![],
+            emit: Emit::Asm,
+            output: None,
+            in_file: false,
+            max_errors: 20,
+            lang: lang_from_args(&args),
+            error_format: ErrorFormat::Human,
+            lexer_table: None,
+            assembler: None,
+            linker: None,
+            target: Target::Elf32Linux,
+            short_circuit: false,
+            checks: Checks::none(),
+            help: false,
+        };
+        let mut emit = None;
+
+        let mut iter = args.into_iter();
+        while let Some(arg) = iter.next() {
+            // --opt=value и --opt value равнозначны
+            let (name, inline) = match arg.find('=') {
+                Some(pos) if arg.starts_with("--") => {
+                    (arg[..pos].to_string(), Some(arg[pos + 1..].to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+            let mut value = || match inline.clone() {
+                Some(val) => Ok(val),
+                None => iter.next().ok_or(UsageError::with_arg("cli.expects_value", name.clone())),
+            };
+
+            let stage = match &*name {
+                "-h" | "--help" => {
+                    res.help = true;
+                    None
+                }
+                "-l" => Some(Emit::Tokens),
+                "-p" => Some(Emit::Ast),
+                "-g" => Some(Emit::Asm),
+                "--emit" => {
+                    let val = try!(value());
+                    match Emit::from_name(&val) {
+                        Some(stage) => Some(stage),
+                        None => return Err(UsageError::with_arg("cli.unknown_emit", val)),
+                    }
+                }
+                "-o" => {
+                    if res.output.is_some() {
+                        return Err(UsageError::new("cli.repeated_output"));
+                    }
+                    res.output = Some(try!(value()));
+                    None
+                }
+                "-f" => {
+                    res.in_file = true;
+                    None
+                }
+                "-e" => {
+                    let val = try!(value());
+                    match val.parse::<usize>() {
+                        Ok(count) => res.max_errors = count,
+                        Err(_) => return Err(UsageError::new("cli.expects_count")),
+                    }
+                    None
+                }
+                "--lang" => {
+                    let val = try!(value());
+                    match Lang::from_name(&val) {
+                        Some(lang) => res.lang = lang,
+                        None => return Err(UsageError::new("cli.expects_lang")),
+                    }
+                    None
+                }
+                "--error-format" => {
+                    let val = try!(value());
+                    match ErrorFormat::from_name(&val) {
+                        Some(format) => res.error_format = format,
+                        None => return Err(UsageError::new("cli.expects_error_format")),
+                    }
+                    None
+                }
+                "--lexer-table" => {
+                    res.lexer_table = Some(try!(value()));
+                    None
+                }
+                "--assembler" => {
+                    res.assembler = Some(try!(value()));
+                    None
+                }
+                "--linker" => {
+                    res.linker = Some(try!(value()));
+                    None
+                }
+                "--target" => {
+                    let val = try!(value());
+                    match Target::from_name(&val) {
+                        Some(target) => res.target = target,
+                        None => return Err(UsageError::with_arg("cli.unknown_target", val)),
+                    }
+                    None
+                }
+                "--short-circuit" => {
+                    res.short_circuit = true;
+                    None
+                }
+                "--checks" => {
+                    let val = try!(value());
+                    match Checks::from_list(&val) {
+                        Some(checks) => res.checks = checks,
+                        None => return Err(UsageError::new("cli.expects_checks")),
+                    }
+                    None
+                }
+                "-" => {
+                    res.inputs.push(arg.clone());
+                    None
+                }
+                _ if arg.starts_with("-") => return Err(UsageError::with_arg("cli.unknown_option", arg.clone())),
+                _ => {
+                    res.inputs.push(arg.clone());
+                    None
+                }
+            };
+
+            if let Some(stage) = stage {
+                match emit {
+                    Some(prev) if prev != stage => {
+                        return Err(UsageError::new("cli.exclusive_stages"))
+                    }
+                    _ => emit = Some(stage),
+                }
+            }
+        }
+
+        if res.help {
+            return Ok(res);
+        }
+        if res.inputs.is_empty() {
+            return Err(UsageError::new("cli.no_input"));
+        }
+        if res.output.is_some() && res.inputs.len() > 1 {
+            return Err(UsageError::new("cli.output_with_many_inputs"));
+        }
+        if res.output.is_some() && res.in_file {
+            return Err(UsageError::new("cli.output_with_in_file"));
+        }
+        if res.inputs.iter().filter(|input| *input == "-").count() > 1 {
+            return Err(UsageError::new("cli.stdin_twice"));
+        }
+        if let Some(stage) = emit {
+            res.emit = stage;
+        }
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,107 @@
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::path::Path;
+use std::process::Command;
+use PasC::GeneratorPack::Target;
+use PasC::DiagnosticPack::*;
+
+pub struct ToolError {
+    pub key: &'static str,
+    pub args: Vec<String>,
+}
+
+impl ToolError {
+    pub fn message(&self, lang: Lang) -> String {
+        format_message(self.key, &self.args, lang)
+    }
+}
+
+pub struct Toolchain {
+    pub assembler: String,
+    pub linker: String,
+    pub target: Target,
+}
+
+impl Toolchain {
+    pub fn new() -> Toolchain {
+        Toolchain {
+            assembler: "nasm".to_string(),
+            linker: "gcc".to_string(),
+            target: Target::Elf32Linux,
+        }
+    }
+
+    pub fn assemble(&self, asm_path: &str, obj_path: &str) -> Result<(), ToolError> {
+        let args = vec!["-f", self.target.object_format(), "-o", obj_path, asm_path];
+        run_tool(&self.assembler, &args)
+    }
+
+    pub fn link(&self, obj_path: &str, exe_path: &str) -> Result<(), ToolError> {
+        let args = vec![self.target.machine_flag(), "-o", exe_path, obj_path];
+        run_tool(&self.linker, &args)
+    }
+
+    pub fn build(&self, asm: &str, output: &str, link: bool) -> Result<(), ToolError> {
+        let asm_path = side_path(output, "asm");
+        if let Err(_) = File::create(&asm_path).and_then(|mut file| file.write_all(asm.as_bytes())) {
+            return Err(ToolError {
+                key: "io.cannot_write",
+                args: vec![asm_path],
+            });
+        }
+
+        if !link {
+            return self.assemble(&asm_path, output);
+        }
+
+        let obj_path = side_path(output, "o");
+        try!(self.assemble(&asm_path, &obj_path));
+        let res = self.link(&obj_path, output);
+        if obj_path != with_extension(output, "o") {
+            let _ = fs::remove_file(&obj_path);
+        }
+        res
+    }
+}
+
+pub fn with_extension(path: &str, extension: &str) -> String {
+    Path::new(path)
+        .with_extension(extension)
+        .to_string_lossy()
+        .into_owned()
+}
+
+// Промежуточный файл рядом с результатом; если имена совпали, берется временное,
+// иначе инструмент перезапишет собственный вход
+fn side_path(output: &str, extension: &str) -> String {
+    let path = with_extension(output, extension);
+    if path == output {
+        return with_extension(output, &("tmp.".to_string() + extension));
+    }
+    path
+}
+
+fn run_tool(tool: &str, args: &Vec<&str>) -> Result<(), ToolError> {
+    match Command::new(tool).args(args).status() {
+        Ok(status) => {
+            if status.success() {
+                Ok(())
+            } else {
+                Err(ToolError {
+                    key: "tool.failed",
+                    args: vec![tool.to_string() + " " + &args.join(" "), status.to_string()],
+                })
+            }
+        }
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => Err(ToolError {
+            key: "tool.not_found",
+            args: vec![tool.to_string()],
+        }),
+        Err(err) => Err(ToolError {
+            key: "tool.cannot_run",
+            args: vec![tool.to_string(), err.to_string()],
+        }),
+    }
+}
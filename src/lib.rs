@@ -0,0 +1,167 @@
+#[macro_use]
+extern crate NameByField;
+#[macro_use]
+extern crate lazy_static;
+
+pub mod TokenizerPack;
+pub mod ParserPack;
+pub mod SemanticPack;
+pub mod GeneratorPack;
+pub mod DiagnosticPack;
+pub mod support;
+
+use support::*;
+use TokenizerPack::csv_parser::TransitionTable;
+use TokenizerPack::token::Token;
+use TokenizerPack::tokenizer::Tokenizer;
+use ParserPack::parser::Parser;
+use ParserPack::tree::Tree;
+use GeneratorPack::generator::Generator;
+use GeneratorPack::target::Target;
+use GeneratorPack::checks::Checks;
+use DiagnosticPack::Lang;
+
+pub struct Options {
+    pub max_errors: usize,
+    pub lexer_table: Option<TransitionTable>,
+    pub target: Target,
+    pub short_circuit: bool,
+    pub checks: Checks,
+    pub lang: Lang,
+}
+
+impl Options {
+    pub fn new() -> Options {
+        Options {
+            max_errors: 20,
+            lexer_table: None,
+            target: Target::Elf32Linux,
+            short_circuit: false,
+            checks: Checks::none(),
+            lang: Lang::Ru,
+        }
+    }
+
+    pub fn create_tokenizer(&self, source: &str) -> Tokenizer {
+        let mut tokenizer = Tokenizer::from_str(source);
+        if let Some(ref t_table) = self.lexer_table {
+            tokenizer.set_transition_table(t_table.clone());
+        }
+
+        tokenizer
+    }
+}
+
+pub fn tokenize(source: &str) -> Result<Vec<Token>, CompilerErrors> {
+    tokenize_with(source, &Options::new())
+}
+
+pub fn tokenize_with(source: &str, options: &Options) -> Result<Vec<Token>, CompilerErrors> {
+    let mut tokens = vec![];
+    for res in options.create_tokenizer(source) {
+        match res {
+            Ok(token) => tokens.push(token),
+            Err(err) => return Err(CompilerErrors::TokenizerError { err }),
+        }
+    }
+
+    Ok(tokens)
+}
+
+pub fn parse(source: &str) -> Result<Tree, Vec<CompilerErrors>> {
+    parse_with(source, &Options::new())
+}
+
+pub fn parse_with(source: &str, options: &Options) -> Result<Tree, Vec<CompilerErrors>> {
+    let mut tokenizer = options.create_tokenizer(source);
+    tokenizer.next();
+
+    let mut parser = Parser::new(tokenizer);
+    parser.max_errors = options.max_errors;
+    parser.parse().map(|tree| tree.fold_constants())
+}
+
+pub fn generate_with(source: &str, options: &Options) -> Result<Generator, Vec<CompilerErrors>> {
+    let tree = try!(parse_with(source, options));
+
+    let mut generator = Generator::with_target(options.target);
+    generator.short_circuit = options.short_circuit;
+    generator.checks = options.checks;
+    generator.lang = options.lang;
+    tree.generate(&mut generator);
+
+    Ok(generator)
+}
+
+pub fn compile_to_ir(source: &str, options: &Options) -> Result<String, Vec<CompilerErrors>> {
+    generate_with(source, options).map(|generator| generator.program.to_string())
+}
+
+pub fn compile_to_asm(source: &str, options: &Options) -> Result<String, Vec<CompilerErrors>> {
+    generate_with(source, options).map(|generator| generator.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use TokenizerPack::support::TokenType;
+
+    fn token_types(source: &str) -> Vec<TokenType> {
+        tokenize(source).ok().unwrap().into_iter().map(|t| t.token_type).collect()
+    }
+
+    #[test]
+    fn options_default_to_elf32_without_checks() {
+        let options = Options::new();
+        assert_eq!(options.max_errors, 20);
+        assert_eq!(options.target, Target::Elf32Linux);
+        assert!(!options.short_circuit);
+        assert_eq!(options.checks, Checks::none());
+        assert!(options.lang == Lang::Ru);
+    }
+
+    #[test]
+    fn tokenize_assignment() {
+        let types = token_types("a := 1 + 2.5;");
+        assert!(
+            types
+                == vec![
+                    TokenType::TId,
+                    TokenType::TAssign,
+                    TokenType::TInt,
+                    TokenType::TPlus,
+                    TokenType::TDouble,
+                    TokenType::TSemicolom,
+                ]
+        );
+    }
+
+    #[test]
+    fn tokenize_comparisons_are_not_swapped() {
+        let types = token_types("a < b > c");
+        assert!(types == vec![TokenType::TId, TokenType::TLt, TokenType::TId, TokenType::TGt, TokenType::TId]);
+    }
+
+    #[test]
+    fn tokenize_keeps_values_and_coords() {
+        let tokens = tokenize("x\n  $ff").ok().unwrap();
+        assert_eq!(tokens[1].value.as_int(), 255);
+        assert_eq!((tokens[1].coords.y, tokens[1].coords.x), (2, 3));
+    }
+
+    #[test]
+    fn tokenize_reports_too_long_integer() {
+        match tokenize("a := 99999999999999999999;") {
+            Err(CompilerErrors::TokenizerError {
+                err: TokenizerErrors::ErrorInInteger { x: 6, y: 1 },
+            }) => {}
+            _ => panic!("ожидалась ошибка в целом числе"),
+        }
+    }
+
+    #[test]
+    fn parse_checks_semantics() {
+        assert!(parse("var a: integer; begin a := 1; end.").is_ok());
+        assert_eq!(parse("begin a := 1; end.").err().unwrap().len(), 1);
+    }
+}
@@ -1,237 +1,246 @@
-#[macro_use]
-extern crate NameByField;
-#[macro_use]
-extern crate lazy_static;
+extern crate PasC;
 
-mod TokenizerPack;
-mod ParserPack;
-mod SemanticPack;
-mod GeneratorPack;
-mod support;
+mod cli;
+mod driver;
 
 use std::env;
 use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::process;
 use std::io::prelude::*;
-use support::*;
-use TokenizerPack::tokenizer::Tokenizer;
-use ParserPack::*;
-use GeneratorPack::*;
-
-fn procerr_errors(err: CompilerErrors) -> String {
-    match err {
-        CompilerErrors::TokenizerError{err} => match err {
-            TokenizerErrors::ErrorInInteger{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неверный формат целого числа", x, y)
-            },
-            TokenizerErrors::ErrorInHex{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неверный формат шестнадцатеричного числа", x, y)
-            },
-            TokenizerErrors::ErrorInBin{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неверный формат двоичного числа", x, y)
-            },
-            TokenizerErrors::ErrorInOctal{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неверный формат восьмиричного числа", x, y)
-            },
-            TokenizerErrors::ErrorInDouble{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неверный формат вешественного числа", x, y)
-            },
-            TokenizerErrors::ErrorInExp{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неверный формат экспоненты", x, y)
-            },
-            TokenizerErrors::UnknownCharCode{ x, y } => { 
-                format!("Ошибка в ({}, {}): Неизвестный код символа", x, y)
-            },
-            TokenizerErrors::SimpleError{ x, y } => { 
-                format!("Ошибка в ({}, {})", x, y)
-            },
-        },
-        CompilerErrors::ParserError{err} => match err {
-            ParserErrors::MissingOperand{ x, y } => { 
-                format!("Ошибка в ({}, {}): Пропущен операнд", x, y)
-            },
-            ParserErrors::ExpectedToken{ x, y, token } => {
-                format!("Ошибка в ({}, {}): Ожидалось {}", x, y, token)
-            },
-        },
-        CompilerErrors::SemanticError{err} => match err {
-            SemanticErrors::CastError{ this, other } => { 
-                format!("Ошибка: Нельзя преобразовать {} в {}", this, other)
-            },
-            SemanticErrors::DuplicateIdentifier{ name } => { 
-                format!("Ошибка: Идентификатор {} уже определен", name)
-            },
-            SemanticErrors::UnknownOverride{ name, sign } => { 
-                format!("Ошибка: Не найдена перегрузка {} для {}", sign, name)
-            },
-            SemanticErrors::NotAFunction{ name } => { 
-                format!("Ошибка: Нельзя вызвать {} как функцию", name)
-            },
-            SemanticErrors::ErrorInForwardDecl{ name, sign } => { 
-                format!("Ошибка: Не найдено определение для {}{} ", name, sign)
-            },
-            SemanticErrors::UnknownIdentifier{ name } => { 
-                format!("Ошибка: Идентификатор {} неизвестен", name)
-            },
-            SemanticErrors::ErrorInUnarOperation { name, op } => { 
-                let msg = match op {
-                    UnarOperation::Plus => { "унарный плюс".to_string() }, 
-                    UnarOperation::Minus => { "унарный минус".to_string() }, 
-                    UnarOperation::Not => { "унарное not".to_string() }
-                };
-                format!("Ошибка: Невозможно применить {} к {}", msg, name)
-            },
-            SemanticErrors::ErrorInBinOperation { left, right, op } => { 
-                let msg = match op {
-                    BinOperation::Plus  => { "сложить".to_string() }, 
-                    BinOperation::Minus => { "отнять".to_string() }, 
-                    BinOperation::Mul   => { "умножить".to_string() },
-                    BinOperation::Share => { "поделить".to_string() }, 
-                    BinOperation::And   => { "применить And".to_string() }, 
-                    BinOperation::Or    => { "применить Or".to_string() },
-                    _ =>  { "сравнить".to_string() },
-                };
-                format!("Ошибка: Невозможно {} {} c {}", msg, left, right)
-            },
-            SemanticErrors::OtherError{ msg } => { 
-                format!("Ошибка: {}", msg)
-            },
-        },
+use PasC::support::*;
+use PasC::TokenizerPack::csv_parser;
+use PasC::DiagnosticPack::*;
+use PasC::Options;
+use cli::*;
+use driver::*;
+
+fn procerr_error_list(
+    errors: Vec<CompilerErrors>,
+    file_name: &str,
+    source: &str,
+    lang: Lang,
+    format: &ErrorFormat,
+) -> String {
+    let lines: Vec<String> = source.lines().map(|line| line.to_string()).collect();
+
+    let messages: Vec<String> = errors
+        .into_iter()
+        .map(|err| {
+            let diagnostic = Diagnostic::new(err, lang);
+            match *format {
+                ErrorFormat::Human => diagnostic.render(file_name, &lines),
+                ErrorFormat::Json => diagnostic.to_json(file_name),
+            }
+        })
+        .collect();
+    messages.join("\n")
+}
+
+fn read_source(file: &str) -> io::Result<String> {
+    let mut source = String::new();
+    if file == "-" {
+        try!(io::stdin().read_to_string(&mut source));
+    } else {
+        try!(try!(File::open(file)).read_to_string(&mut source));
     }
+
+    Ok(source)
 }
 
-fn main() {
-    let mut tokenizer_mode = false;
-    let mut parser_mode = false;
-    let mut generator_mode = false;
-    let mut infile_mode = false;
-    let mut file = "".to_string();
+fn res_file_name(file: &str) -> String {
+    if file == "-" {
+        return "stdin.res".to_string();
+    }
+    Path::new(file).with_extension("res").to_string_lossy().into_owned()
+}
 
-    if env::args().len() == 1 {
-        println!("Приходько Олег. 2017 год.");
+fn exit_code(errors: &Vec<CompilerErrors>) -> i32 {
+    if errors.is_empty() {
+        return 0;
     }
 
-    for arg in env::args() {
-        if arg[0..1].to_string() != "-" {
-            file = arg.to_string();
-        }
-        if arg == "-h" {
-            println!("Приходько Олег. 2017 год.");
-            println!("-h -> help");
-            println!("-l file -> run tokenizer in file");
-            return;
-        }
-        if arg == "-f" {
-            infile_mode = true;
-        }
+    let mut code = EXIT_SEMANTIC;
+    for err in errors {
+        code = match *err {
+            CompilerErrors::TokenizerError { .. } => EXIT_LEXICAL,
+            CompilerErrors::ParserError { .. } if code != EXIT_LEXICAL => EXIT_SYNTAX,
+            _ => code,
+        };
+    }
+
+    code
+}
 
-        if arg == "-l" && !parser_mode {
-            tokenizer_mode = true;
+fn tokens_listing(source: &str, options: &Options) -> (String, Vec<CompilerErrors>) {
+    let mut lines = vec![format!(
+        "\t{:6} {:6} {:15} {:25} {:25}",
+        "Line", "Col", "Type", "Value", "Text"
+    )];
+    for res in options.create_tokenizer(source) {
+        match res {
+            Ok(token) => lines.push(token.to_string()),
+            Err(err) => {
+                return (lines.join("\n"), vec![CompilerErrors::TokenizerError { err }]);
+            }
         }
+    }
+
+    (lines.join("\n"), vec![])
+}
+
+fn fatal(msg: &str, lang: Lang) {
+    eprintln!("{}: {}", message("label.fatal", lang), msg);
+}
 
-        if arg == "-p" && !tokenizer_mode {
-            parser_mode = true;
+fn write_output(input: &str, args: &Args, text: &str) -> i32 {
+    let path = match args.output {
+        Some(ref path) => Some(path.clone()),
+        None if args.in_file => Some(res_file_name(input)),
+        None => None,
+    };
+
+    match path {
+        Some(path) => {
+            if let Err(_) = File::create(&path).and_then(|mut file| file.write_all(text.as_bytes())) {
+                fatal(&format_message("io.cannot_write", &[path], args.lang), args.lang);
+                return EXIT_IO;
+            }
         }
+        None => println!("{}", text),
+    }
+
+    0
+}
 
-        if arg == "-g" && !tokenizer_mode {
-            generator_mode = true;
+fn build_binary(input: &str, args: &Args, asm: &str) -> i32 {
+    let link = args.emit == Emit::Exe;
+    let output = match args.output {
+        Some(ref path) => path.clone(),
+        None => {
+            let input = if input == "-" { "stdin" } else { input };
+            let output = with_extension(input, if link { "" } else { "o" });
+            // не перезаписываем исходник без расширения
+            if output == input { output + ".out" } else { output }
         }
+    };
+
+    let mut toolchain = Toolchain::new();
+    toolchain.target = args.target;
+    if let Some(ref assembler) = args.assembler {
+        toolchain.assembler = assembler.clone();
+    }
+    if let Some(ref linker) = args.linker {
+        toolchain.linker = linker.clone();
     }
 
-    if tokenizer_mode {
-        let tokenizer = Tokenizer::new(file.clone());
-        let mstr = file[0..file.len() - 4].to_string() + ".res";
-
-        if infile_mode {
-            let mut file = File::create(mstr).unwrap();
-            file.write_fmt(format_args!(
-                "\t{:6} {:6} {:15} {:25} {:25}\n",
-                "Line", "Col", "Type", "Value", "Text"
-            )).unwrap();
-            for res in tokenizer {
-                match res {
-                    Ok(token) => {
-                        file.write_fmt(format_args!("{}\n", token)).unwrap();
-                    }
-                    Err(err) => {
-                        let error = CompilerErrors::TokenizerError { err };
-                        file.write_fmt(format_args!("{}\n", procerr_errors(error)))
-                            .unwrap();
-                        break;
-                    }
-                }
-            }
-        } else {
-            println!(
-                "\t{:6} {:6} {:15} {:25} {:25}",
-                "Line", "Col", "Type", "Value", "Text"
-            );
-            for res in tokenizer {
-                match res {
-                    Ok(token) => {
-                        println!("{}", token);
-                    }
-                    Err(err) => {
-                        let error = CompilerErrors::TokenizerError { err };
-                        println!("{}", procerr_errors(error));
-                        break;
-                    }
-                }
-            }
+    match toolchain.build(asm, &output, link) {
+        Ok(_) => 0,
+        Err(err) => {
+            fatal(&err.message(args.lang), args.lang);
+            EXIT_TOOL
         }
-    } else if parser_mode {
-        let mut tokenizer = Tokenizer::new(file.clone());
-        tokenizer.next();
-        let mut parser = Parser::new(tokenizer);
+    }
+}
 
-        let mstr = file[0..file.len() - 4].to_string() + ".res";
-        let mut file = File::create(mstr).unwrap();
+fn compile_file(input: &str, args: &Args, options: &Options) -> i32 {
+    let source = match read_source(input) {
+        Ok(val) => val,
+        Err(_) => {
+            fatal(&format_message("io.cannot_read", &[input.to_string()], args.lang), args.lang);
+            return EXIT_IO;
+        }
+    };
+    let file_name = if input == "-" { "<stdin>" } else { input };
+
+    let (text, errors) = match args.emit {
+        Emit::Tokens => tokens_listing(&source, options),
+        Emit::Ast => match PasC::parse_with(&source, options) {
+            Ok(tree) => (tree.to_string(), vec![]),
+            Err(errors) => ("".to_string(), errors),
+        },
+        Emit::Ir => match PasC::compile_to_ir(&source, options) {
+            Ok(ir) => (ir, vec![]),
+            Err(errors) => ("".to_string(), errors),
+        },
+        _ => match PasC::compile_to_asm(&source, options) {
+            Ok(asm) => (asm, vec![]),
+            Err(errors) => ("".to_string(), errors),
+        },
+    };
+
+    let code = exit_code(&errors);
+    if !errors.is_empty() {
+        eprintln!(
+            "{}",
+            procerr_error_list(errors, file_name, &source, args.lang, &args.error_format)
+        );
+    }
 
-        let tree = match parser.parse() {
-            Ok(val) => val,
-            Err(err) => {
-                if infile_mode {
-                    file.write_fmt(format_args!("{}", procerr_errors(err)))
-                        .unwrap();
-                } else {
-                    println!("{}", procerr_errors(err));
-                }
-                return;
-            }
-        };
+    match args.emit {
+        Emit::Obj | Emit::Exe if code == 0 => return build_binary(input, args, &text),
+        _ => {}
+    }
 
-        if infile_mode {
-            file.write_fmt(format_args!("{}", tree)).unwrap();
-        } else {
-            println!("{}", tree);
+    // листинг токенов до ошибки все равно полезен
+    if code == 0 || args.emit == Emit::Tokens {
+        let res = write_output(input, args, &text);
+        if code == 0 {
+            return res;
         }
-    } else if generator_mode {
-        let mut tokenizer = Tokenizer::new(file.clone());
-        tokenizer.next();
-        let mut parser = Parser::new(tokenizer);
+    }
 
-        let mstr = file[0..file.len() - 4].to_string() + ".res";
-        let mut file = File::create(mstr).unwrap();
+    code
+}
 
-        let tree = match parser.parse() {
-            Ok(val) => val,
-            Err(err) => {
-                if infile_mode {
-                    file.write_fmt(format_args!("{}", procerr_errors(err)))
-                        .unwrap();
-                } else {
-                    println!("{}", procerr_errors(err));
-                }
-                return;
-            }
-        };
+fn usage_error(err: UsageError, lang: Lang) -> ! {
+    fatal(&err.message(lang), lang);
+    eprintln!("{}", usage(lang));
+    process::exit(EXIT_USAGE);
+}
 
-        let mut generator = Generator::new();
-        tree.generate(&mut generator);
+fn main() {
+    let raw_args: Vec<String> = env::args().skip(1).collect();
+    if raw_args.is_empty() {
+        println!("Приходько Олег. 2017 год.");
+        println!("{}", usage(Lang::from_env()));
+        return;
+    }
 
-        if infile_mode {
-            file.write_fmt(format_args!("{}", generator)).unwrap();
-        } else {
-            println!("{}", generator);
+    let lang = lang_from_args(&raw_args);
+    let args = match Args::parse(raw_args) {
+        Ok(val) => val,
+        Err(err) => usage_error(err, lang),
+    };
+    if args.help {
+        println!("Приходько Олег. 2017 год.");
+        println!("{}", usage(args.lang));
+        return;
+    }
+
+    let lexer_table = match args.lexer_table {
+        Some(ref path) => match csv_parser::Parser::load_form_csv(path) {
+            Ok(val) => Some(val),
+            Err(_) => usage_error(UsageError::with_arg("io.cannot_read_table", path.clone()), args.lang),
+        },
+        None => None,
+    };
+    let options = Options {
+        max_errors: args.max_errors,
+        lexer_table,
+        target: args.target,
+        short_circuit: args.short_circuit,
+        checks: args.checks,
+        lang: args.lang,
+    };
+
+    let mut code = 0;
+    for input in &args.inputs {
+        let res = compile_file(input, &args, &options);
+        if code == 0 {
+            code = res;
         }
     }
+    process::exit(code);
 }
@@ -1,11 +1,13 @@
 use ParserPack::Types::support::*;
+use TokenizerPack::support::Point;
 
 pub enum CompilerErrors {
     TokenizerError { err: TokenizerErrors },
     ParserError { err: ParserErrors },
-    SemanticError { err: SemanticErrors },
+    SemanticError { err: SemanticErrors, x: i32, y: i32, len: i32 },
 }
 
+#[derive(name_by_field)]
 pub enum TokenizerErrors {
     ErrorInInteger { x: i32, y: i32 },
     ErrorInHex { x: i32, y: i32 },
@@ -17,15 +19,18 @@ pub enum TokenizerErrors {
     SimpleError { x: i32, y: i32 },
 }
 
+#[derive(name_by_field)]
 pub enum ParserErrors {
-    MissingOperand { x: i32, y: i32 },
-    ExpectedToken { x: i32, y: i32, token: String },
+    MissingOperand { x: i32, y: i32, len: i32 },
+    ExpectedToken { x: i32, y: i32, len: i32, token: String },
+    TooManyErrors { count: usize },
 }
 
-#[derive(Debug)]
+#[derive(Debug, name_by_field)]
 pub enum SemanticErrors {
     DuplicateIdentifier {
         name: String,
+        prev: Point,
     },
     UnknownIdentifier {
         name: String,
@@ -37,6 +42,10 @@ pub enum SemanticErrors {
         name: String,
         sign: String,
     },
+    AmbiguousOverride {
+        name: String,
+        signs: Vec<String>,
+    },
     CastError {
         this: String,
         other: String,
@@ -54,14 +63,65 @@ pub enum SemanticErrors {
         name: String,
         sign: String,
     },
-    OtherError {
-        msg: String,
+    InvalidConversion {
+        from: String,
+        to: String,
+    },
+    NotIndexable {
+        name: String,
+    },
+    NoFields {
+        name: String,
+    },
+    UnknownField {
+        field: String,
+        name: String,
+    },
+    InvalidIndexType {
+        index: String,
+        expected: String,
+    },
+    InvalidRangeBounds {
+        left: String,
+        right: String,
+    },
+    IndexOutOfRange {
+        index: String,
+        expected: String,
+    },
+    ArgCountMismatch {
+        expected: usize,
+        passed: usize,
+    },
+    NotInLoop {
+        kind: String,
+    },
+    AggregateByValue {
+        name: String,
     },
+    ExpectedType,
+    ExpectedEnumerated,
+    ExpectedBoolean,
+    ExpectedBinOperator,
+    ExpectedUnarOperator,
+    ExpectedAssignOperator,
+    ArrayTooLarge,
+    InvalidRange,
+    InvalidExpressionType,
+    InvalidExpression,
+    InvalidOverride,
+    ExpectedConstant,
+    ExpectedVariable,
 }
 
-impl From<SemanticErrors> for CompilerErrors {
-    fn from(err: SemanticErrors) -> Self {
-        CompilerErrors::SemanticError { err }
+impl SemanticErrors {
+    pub fn at(self, coords: &Point) -> CompilerErrors {
+        CompilerErrors::SemanticError {
+            err: self,
+            x: coords.x,
+            y: coords.y,
+            len: coords.len,
+        }
     }
 }
 
@@ -0,0 +1,114 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use PasC::GeneratorPack::target::Target;
+use common::*;
+
+const TYPES: &str = "
+type arr = array [1..3] of integer;
+     pt = record x: integer; d: double; end;
+     m = array [1..2] of pt;
+";
+
+fn program(decls: &str, body: &str) -> String {
+    TYPES.to_string() + decls + "\nbegin\n" + body + "\nend.\n"
+}
+
+fn data_lines(ir: &str) -> Vec<String> {
+    ir.lines().filter(|line| line.starts_with('@')).map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn constant_array_keeps_its_elements() {
+    let ir = ir(&program("const A: arr = [1, 2, 3];", "writeln(A[2]);"));
+    assert_eq!(data_lines(&ir), vec!["@A : words [i 1, i 2, i 3]"]);
+}
+
+#[test]
+fn record_and_nested_initializers_follow_field_order() {
+    let ir = ir(&program(
+        "var P: pt = (x: 5, d: 2.5);\n    M2: m = [(x: 1, d: 1), (x: 2, d: 0.5)];",
+        "writeln(P.x);",
+    ));
+    assert_eq!(
+        data_lines(&ir),
+        vec![
+            "@P : words [i 5, d 2.5]",
+            "@M2 : words [i 1, d 1.0, i 2, d 0.5]",
+        ]
+    );
+}
+
+#[test]
+fn uninitialized_aggregate_is_zero_filled() {
+    let ir = ir(&program("var B: arr; Q: pt;", "writeln(B[1]);"));
+    assert_eq!(data_lines(&ir), vec!["@B : bytes 12", "@Q : bytes 12"]);
+}
+
+#[test]
+fn words_match_field_offsets_on_each_target() {
+    let source = program("var P: pt = (x: 5, d: 2.5);", "writeln(P.d);");
+    let asm32 = asm(&source);
+    assert!(asm32.contains("v_P : dd 5\n    dq 0x4004000000000000\n"));
+
+    let mut options = Options::new();
+    options.target = Target::Elf64Linux;
+    let asm64 = asm_with(&source, &options);
+    assert!(asm64.contains("v_P : dq 5\n    dq 0x4004000000000000\n"));
+}
+
+#[test]
+fn local_aggregate_is_filled_on_entry() {
+    let source = program(
+        "procedure q;\nvar L: arr = [7, 8, 9];\nbegin\n  writeln(L[2]);\nend;",
+        "q;",
+    );
+    let body = ir_function(&ir(&source), "function f_q_0 (args 0, locals 12)");
+    let stores: Vec<&str> = body[1..7].iter().map(|line| line.as_str()).collect();
+    assert_eq!(
+        stores,
+        vec!["push.i 7", "store.i [fp-12]", "push.i 8", "store.i [fp-8]", "push.i 9", "store.i [fp-4]"]
+    );
+}
+
+#[test]
+fn initializer_must_be_constant() {
+    let source = program("var i: integer;\n    A: arr = [1, i, 3];", "writeln(i);");
+    assert_eq!(parse_codes(&source), vec!["E0230"]);
+}
+
+#[test]
+fn initializer_prints_in_tree() {
+    let tree = parse(&program("const A: arr = [1, 2, 3];", "writeln(A[2]);")).ok().unwrap().to_string();
+    assert!(tree.contains("A : Array[1..3] of Integer = [1, 2, 3]"));
+}
+
+#[test]
+fn record_is_not_passed_by_value() {
+    let source = program(
+        "var P: pt;\nprocedure show(r: pt);\nbegin\n  writeln(r.x, r.d);\nend;",
+        "P.x := 1;\nshow(P);",
+    );
+    assert_eq!(parse_codes(&source), vec!["E0232"]);
+}
+
+#[test]
+fn array_is_not_passed_by_value() {
+    let source = program(
+        "var B: arr;\nfunction first(a: arr): integer;\nbegin\n  first := a[1];\nend;",
+        "writeln(first(B));",
+    );
+    assert_eq!(parse_codes(&source), vec!["E0232"]);
+}
+
+#[test]
+fn aggregate_is_passed_as_var_parameter() {
+    let source = program(
+        "var P: pt;\nprocedure show(var r: pt);\nbegin\n  writeln(r.x, r.d);\nend;",
+        "P.x := 1;\nshow(P);",
+    );
+    let main = ir_function(&ir(&source), "main main");
+    assert_eq!(main[4..].to_vec(), vec!["addr @P", "push.i 0", "call f_show_0"]);
+}
@@ -0,0 +1,111 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use PasC::GeneratorPack::target::Target;
+use common::*;
+
+const PROGRAM: &str = "
+var a: integer;
+begin
+  a := 1 + 2 * a;
+  writeln(a);
+end.
+";
+
+fn options_for(target: Target) -> Options {
+    let mut options = Options::new();
+    options.target = target;
+    options
+}
+
+#[test]
+fn tokenize_program() {
+    let tokens = tokenize(PROGRAM).ok().unwrap();
+    let texts: Vec<String> = tokens.iter().take(5).map(|t| t.text.clone()).collect();
+    assert_eq!(texts, vec!["var", "a", ":", "integer", ";"]);
+    assert_eq!(tokens.last().unwrap().text, ".");
+}
+
+#[test]
+fn parse_prints_tree() {
+    let tree = parse(PROGRAM).ok().unwrap().to_string();
+    let lines: Vec<&str> = tree.lines().map(|line| line.trim_start_matches(|c| "│├└─ ".contains(c))).collect();
+    assert_eq!(lines[0], "Program");
+    assert!(lines.contains(&"a : Integer = Undefined"));
+    assert!(lines.contains(&":="));
+    assert!(lines.contains(&"Writeln"));
+}
+
+#[test]
+fn parse_returns_all_errors() {
+    let source = "
+begin
+  a := 1;
+  b := 2;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0202", "E0202"]);
+}
+
+#[test]
+fn compile_to_ir_lists_data_and_main() {
+    let ir = ir(PROGRAM);
+    assert!(ir.starts_with("extern printf\n"));
+    assert!(ir.contains("@a : i = 0"));
+    assert!(ir.contains("fmt_0 : format \"%d\""));
+    assert_eq!(
+        ir_function(&ir, "main main"),
+        vec![
+            ".b0:",
+            "push.i 1",
+            "push.i 2",
+            "load.i @a",
+            "op.i *",
+            "op.i +",
+            "store.i @a",
+            "load.i @a",
+            "printf fmt_0 (i)",
+        ]
+    );
+}
+
+#[test]
+fn compile_to_ir_does_not_depend_on_target_word() {
+    let ir32 = ir_with(PROGRAM, &options_for(Target::Elf32Linux));
+    let ir64 = ir_with(PROGRAM, &options_for(Target::Elf64Linux));
+    assert_eq!(ir_function(&ir32, "main main"), ir_function(&ir64, "main main"));
+}
+
+#[test]
+fn compile_to_asm_for_elf32() {
+    let asm = asm(PROGRAM);
+    assert!(asm.starts_with("extern printf"));
+    assert!(asm.contains("v_a : dd 0"));
+    assert!(asm.contains("global main"));
+    assert!(asm.contains("push dword [v_a]"));
+}
+
+#[test]
+fn compile_to_asm_for_elf64() {
+    let asm = asm_with(PROGRAM, &options_for(Target::Elf64Linux));
+    assert!(asm.starts_with("bits 64\ndefault rel\n"));
+    assert!(asm.contains("v_a : dq 0"));
+    assert!(asm.contains("\"%ld\""));
+}
+
+#[test]
+fn compile_to_asm_prefixes_symbols_for_win32_and_macho() {
+    for target in vec![Target::Win32, Target::Macho32] {
+        let asm = asm_with(PROGRAM, &options_for(target));
+        assert!(asm.contains("extern _printf"));
+        assert!(asm.contains("global _main"));
+    }
+}
+
+#[test]
+fn compile_reports_errors() {
+    assert!(compile_to_ir("begin a := 1; end.", &Options::new()).is_err());
+    assert!(compile_to_asm("begin a := 1; end.", &Options::new()).is_err());
+}
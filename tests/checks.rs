@@ -0,0 +1,97 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use PasC::GeneratorPack::checks::Checks;
+use PasC::DiagnosticPack::Lang;
+use common::*;
+
+const INDEXING: &str = "
+var a: array [1..3] of integer; i: integer;
+begin
+  i := 2;
+  a[i] := i + 1;
+end.
+";
+
+const NARROWING: &str = "
+type small = 1..10;
+var s: small; i: integer;
+begin
+  i := 5;
+  s := i;
+end.
+";
+
+fn with_checks(list: &str) -> Options {
+    let mut options = Options::new();
+    options.checks = Checks::from_list(list).unwrap();
+    options
+}
+
+#[test]
+fn no_checks_by_default() {
+    let ir = ir(INDEXING);
+    assert!(!ir.contains("guard"));
+    assert!(!ir.contains("jno"));
+    assert!(!ir.contains("check_failed"));
+}
+
+#[test]
+fn bounds_check_guards_index() {
+    let main = ir_function(&ir_with(INDEXING, &with_checks("bounds")), "main main");
+    let guard = main.iter().position(|line| line == "guard 1..3 l_0 else l_1").unwrap();
+    assert_eq!(
+        &main[guard + 1..guard + 6],
+        &["l_1:", "push.i 5", "addr check_bounds", "call check_failed", "l_0:"]
+    );
+}
+
+#[test]
+fn overflow_check_follows_operation() {
+    let main = ir_function(&ir_with(INDEXING, &with_checks("overflow")), "main main");
+    let op = main.iter().position(|line| line == "op.i +").unwrap();
+    assert_eq!(main[op + 1], "jno l_0");
+    assert!(main.contains(&"addr check_overflow".to_string()));
+}
+
+#[test]
+fn range_check_guards_narrowing_assignment() {
+    let main = ir_function(&ir_with(NARROWING, &with_checks("range")), "main main");
+    let tail: Vec<&str> = main[3..].iter().map(|line| line.as_str()).collect();
+    assert_eq!(
+        tail,
+        vec![
+            "load.i @i",
+            "guard 1..10 l_0 else l_1",
+            "l_1:",
+            "push.i 6",
+            "addr check_range",
+            "call check_failed",
+            "l_0:",
+            "store.i @s",
+        ]
+    );
+}
+
+#[test]
+fn failure_routine_is_shared() {
+    let ir = ir_with(INDEXING, &with_checks("bounds,overflow"));
+    assert_eq!(ir.matches("routine check_failed:").count(), 1);
+    assert_eq!(ir.matches(": format").count(), 1);
+    assert_eq!(
+        ir_function(&ir, "routine check_failed"),
+        vec![".b0:", "drop.ret", "printf fmt_0 (i, i)", "exit 1"]
+    );
+}
+
+#[test]
+fn failure_message_follows_lang() {
+    let mut options = with_checks("bounds");
+    assert!(ir_with(INDEXING, &options)
+        .contains("fmt_0 : format \"Ошибка выполнения: проверка %s не пройдена в строке %d\""));
+
+    options.lang = Lang::En;
+    assert!(ir_with(INDEXING, &options).contains("fmt_0 : format \"Runtime error: %s check failed at line %d\""));
+}
@@ -0,0 +1,186 @@
+use std::process::{Command, Output};
+
+fn pasc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_PasC"))
+        .args(args)
+        .env("LANG", "C")
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn usage_error_follows_lang() {
+    let output = pasc(&["--bogus", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(2));
+    let text = stderr(&output);
+    assert!(text.starts_with("Error: unknown option --bogus\nUsage: PasC [options] file...\n"));
+    assert!(text.contains("--target elf32-linux|elf64-linux|win32|macho32 -> target platform"));
+
+    let text = stderr(&pasc(&["--lang=ru", "--bogus"]));
+    assert!(text.starts_with("Ошибка: неизвестная опция --bogus\nИспользование: PasC [опции] файл...\n"));
+    assert!(text.contains("-o path -> записать результат в path"));
+}
+
+#[test]
+fn lang_given_after_error_is_respected() {
+    let text = stderr(&pasc(&["-e", "x", "--lang", "en"]));
+    assert!(text.starts_with("Error: -e expects a number\n"));
+}
+
+#[test]
+fn help_is_translated() {
+    let output = pasc(&["-h", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(0));
+    let text = stdout(&output);
+    assert!(text.contains("-f -> write output to <file>.res"));
+    assert!(text.contains("exit codes: 2 usage"));
+}
+
+#[test]
+fn missing_file_is_io_error() {
+    let output = pasc(&["no_such_file.pas", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(3));
+    assert_eq!(stderr(&output), "Error: cannot read file no_such_file.pas\n");
+
+    let output = pasc(&["no_such_file.pas", "--lang", "ru"]);
+    assert_eq!(stderr(&output), "Ошибка: не удалось прочитать файл no_such_file.pas\n");
+}
+
+#[test]
+fn missing_lexer_table_is_usage_error() {
+    let output = pasc(&["x.pas", "--lexer-table", "no_such_table.csv", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("Error: cannot read transition table no_such_table.csv\n"));
+}
+
+#[test]
+fn malformed_lexer_table_is_usage_error() {
+    let dir = std::env::temp_dir().join(format!("pasc_cli_table_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let tables = [
+        ("empty.csv", ""),
+        ("wide.csv", "#a#b\nstart#end#end#end\n"),
+        ("no_start.csv", "#a#b\nid#id#end\n"),
+        ("unknown_state.csv", "#a#b\nstart#id#end\n"),
+        ("bad_range.csv", "#a#b-\nstart#end#end\n"),
+    ];
+    for &(name, text) in &tables {
+        let path = dir.join(name);
+        std::fs::write(&path, text).unwrap();
+        let path = path.to_str().unwrap().to_string();
+        let output = pasc(&["x.pas", "--lexer-table", &path, "--lang", "en"]);
+        assert_eq!(output.status.code(), Some(2), "{}", name);
+        assert!(stderr(&output).starts_with(&format!("Error: cannot read transition table {}\n", path)));
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+// Каталог с корректной программой, чтобы сборка дошла до ассемблера
+fn program_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("pasc_cli_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("t.pas"), "begin writeln(1); end.").unwrap();
+    dir
+}
+
+#[test]
+fn missing_assembler_is_tool_error() {
+    let dir = program_dir("assembler");
+    let src = dir.join("t.pas");
+    let output = pasc(&[src.to_str().unwrap(), "--emit", "obj", "--assembler", "no_such_nasm", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(7));
+    assert_eq!(
+        stderr(&output),
+        "Error: program no_such_nasm not found, install it or choose another one with the options\n"
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_linker_is_tool_error() {
+    let dir = program_dir("linker");
+    let src = dir.join("t.pas");
+    let output = pasc(&[src.to_str().unwrap(), "--emit", "exe", "--assembler", "true", "--linker", "false", "--lang", "ru"]);
+    assert_eq!(output.status.code(), Some(7));
+    let text = stderr(&output);
+    assert!(text.starts_with("Ошибка: false -m32 -o "));
+    assert!(text.contains("завершился с ошибкой"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn object_file_does_not_overwrite_output() {
+    let dir = program_dir("object_path");
+    let src = dir.join("t.pas");
+    let out = dir.join("x.o");
+    let out = out.to_str().unwrap();
+    let output = pasc(&[src.to_str().unwrap(), "--emit", "exe", "-o", out, "--assembler", "true", "--linker", "echo"]);
+    assert_eq!(output.status.code(), Some(0));
+    let tmp = dir.join("x.tmp.o");
+    assert_eq!(stdout(&output), format!("-m32 -o {} {}\n", out, tmp.to_str().unwrap()));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn emit_ir_prints_listing() {
+    let dir = program_dir("emit_ir");
+    let src = dir.join("t.pas");
+    let output = pasc(&[src.to_str().unwrap(), "--emit", "ir"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "extern printf\n\nfmt_0 : format \"%d\"\n\nmain main:\n.b0:\n    push.i 1\n    printf fmt_0 (i)\n\n"
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn emit_ir_writes_output_file() {
+    let dir = program_dir("emit_ir_file");
+    let src = dir.join("t.pas");
+    let out = dir.join("t.ir");
+    let output = pasc(&[src.to_str().unwrap(), "--emit", "ir", "-o", out.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).is_empty());
+    assert!(std::fs::read_to_string(&out).unwrap().contains("main main:\n"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn emit_ir_follows_checks_and_lang() {
+    let dir = program_dir("emit_ir_checks");
+    let src = dir.join("t.pas");
+    std::fs::write(&src, "var i: integer;\nbegin\n  i := 1;\n  i := i + 1;\nend.").unwrap();
+    let text = stdout(&pasc(&[src.to_str().unwrap(), "--emit", "ir", "--checks", "overflow", "--lang", "en"]));
+    assert!(text.contains("jno l_0\n"));
+    assert!(text.contains("fmt_0 : format \"Runtime error: %s check failed at line %d\"\n"));
+    assert!(text.contains("routine check_failed:\n"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn emit_ir_stops_on_semantic_error() {
+    let dir = program_dir("emit_ir_error");
+    let src = dir.join("t.pas");
+    std::fs::write(&src, "begin x := 1; end.").unwrap();
+    let output = pasc(&[src.to_str().unwrap(), "--emit", "ir", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(stdout(&output).is_empty());
+    assert!(stderr(&output).contains("[E0202]"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn emit_ir_excludes_other_listings() {
+    let output = pasc(&["x.pas", "--emit", "ir", "-g", "--lang", "en"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("Error: -l, -p, -g and --emit are mutually exclusive\n"));
+}
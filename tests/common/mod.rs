@@ -0,0 +1,56 @@
+#![allow(dead_code)]
+
+use PasC::*;
+use PasC::DiagnosticPack::*;
+use PasC::support::*;
+
+pub fn codes(errors: Vec<CompilerErrors>) -> Vec<String> {
+    errors
+        .into_iter()
+        .map(|err| Diagnostic::new(err, Lang::En).code)
+        .collect()
+}
+
+// Коды ошибок, найденных при разборе; пустой список, если программа корректна
+pub fn parse_codes(source: &str) -> Vec<String> {
+    match parse(source) {
+        Ok(_) => vec![],
+        Err(errors) => codes(errors),
+    }
+}
+
+pub fn ir(source: &str) -> String {
+    ir_with(source, &Options::new())
+}
+
+pub fn ir_with(source: &str, options: &Options) -> String {
+    match compile_to_ir(source, options) {
+        Ok(res) => res,
+        Err(errors) => panic!("ошибки компиляции: {:?}", codes(errors)),
+    }
+}
+
+pub fn asm(source: &str) -> String {
+    asm_with(source, &Options::new())
+}
+
+pub fn asm_with(source: &str, options: &Options) -> String {
+    match compile_to_asm(source, options) {
+        Ok(res) => res,
+        Err(errors) => panic!("ошибки компиляции: {:?}", codes(errors)),
+    }
+}
+
+// Строки тела функции label в листинге IR, без заголовка
+pub fn ir_function(ir: &str, label: &str) -> Vec<String> {
+    let mut lines = ir.lines().skip_while(|line| !line.ends_with(&(label.to_string() + ":")));
+    let mut res = vec![];
+    lines.next();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        res.push(line.trim().to_string());
+    }
+    res
+}
@@ -0,0 +1,82 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use PasC::GeneratorPack::target::Target;
+use common::*;
+
+#[test]
+fn integer_operands_are_divided_as_reals() {
+    let source = "
+var i: integer; d: double;
+begin
+  i := 7;
+  d := i / 2;
+end.
+";
+    let main = ir_function(&ir(source), "main main");
+    let tail: Vec<&str> = main[3..].iter().map(|line| line.as_str()).collect();
+    assert_eq!(
+        tail,
+        vec!["load.i @i", "i2d", "push.i 2", "i2d", "fpu.d", "fpu.d", "op.d /", "store.d @d"]
+    );
+}
+
+#[test]
+fn real_quotient_is_not_assignable_to_integer() {
+    let source = "
+var i: integer;
+begin
+  i := 7;
+  i := i / 2;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0210"]);
+}
+
+#[test]
+fn constant_quotient_is_folded_to_real() {
+    let main = ir_function(&ir("begin writeln(7 / 2); end."), "main main");
+    assert_eq!(main, vec![".b0:", "push.d 3.5", "printf fmt_0 (d)"]);
+}
+
+#[test]
+fn division_by_zero_is_left_to_runtime() {
+    let main = ir_function(&ir("begin writeln(1 / 0); end."), "main main");
+    assert!(main.contains(&"op.d /".to_string()));
+}
+
+#[test]
+fn div_and_mod_stay_integer() {
+    let source = "
+var i: integer;
+begin
+  i := 7;
+  i := i div 2 + i mod 2;
+end.
+";
+    let main = ir_function(&ir(source), "main main");
+    assert!(main.contains(&"op.i div".to_string()));
+    assert!(main.contains(&"op.i mod".to_string()));
+}
+
+#[test]
+fn real_division_does_not_use_idiv() {
+    let source = "
+var i: integer; d: double;
+begin
+  i := 7;
+  d := i / 2;
+end.
+";
+    let asm32 = asm(source);
+    assert!(asm32.contains("fdivrp"));
+    assert!(!asm32.contains("idiv"));
+
+    let mut options = Options::new();
+    options.target = Target::Elf64Linux;
+    let asm64 = asm_with(source, &options);
+    assert!(asm64.contains("divsd xmm0, xmm1"));
+    assert!(!asm64.contains("idiv"));
+}
@@ -0,0 +1,82 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use PasC::GeneratorPack::target::Target;
+use common::*;
+
+const FACTORIAL: &str = "
+function fd(n: integer): double;
+begin
+  if n <= 1 then begin fd := 1; end else begin fd := n * fd(n - 1); end;
+end;
+begin
+  writeln(fd(12));
+end.
+";
+
+// Глубина стека x87 перед первой строкой, начинающейся с stop, в теле функции label
+fn x87_depth_before(asm: &str, label: &str, stop: &str) -> i32 {
+    let mut depth = 0;
+    let body = asm.lines().skip_while(|line| *line != label.to_string() + ":").skip(1);
+    for line in body {
+        let line = line.trim();
+        if line.starts_with(stop) {
+            return depth;
+        }
+        let op = line.split_whitespace().next().unwrap_or("");
+        match op {
+            "fld" | "fild" => depth += 1,
+            "fstp" | "fistp" | "fcomip" | "faddp" | "fsubp" | "fsubrp" | "fmulp" | "fdivp" | "fdivrp" => {
+                depth -= 1
+            }
+            _ => {}
+        }
+    }
+    panic!("{} не найдено в {}", stop, label);
+}
+
+#[test]
+fn recursive_operand_is_computed_before_fpu() {
+    let body = ir_function(&ir(FACTORIAL), "function f_fd_0 (args 4, locals 8)");
+    let call = body.iter().position(|line| line == "call f_fd_0").unwrap();
+    let fpu = body.iter().position(|line| line.starts_with("fpu.")).unwrap();
+    assert!(call < fpu);
+    assert_eq!(&body[call + 1..call + 5], &["push.result.d", "fpu.d", "fpu.d", "op.d *"]);
+}
+
+#[test]
+fn x87_stack_is_empty_at_recursive_call() {
+    let asm = asm(FACTORIAL);
+    assert_eq!(x87_depth_before(&asm, "f_fd_0", "call f_fd_0"), 0);
+}
+
+#[test]
+fn operands_keep_their_order() {
+    let source = "
+var e: double; f: double; d: double;
+begin
+  e := 1.5;
+  f := 0.5;
+  d := e - f / e;
+  if e < f then begin writeln(1); end;
+end.
+";
+    let main = ir_function(&ir(source), "main main");
+    let tail: Vec<&str> = main[5..13].iter().map(|line| line.as_str()).collect();
+    assert_eq!(
+        tail,
+        vec!["load.d @e", "load.d @f", "load.d @e", "fpu.d", "fpu.d", "op.d /", "fpu.d", "fpu.d"]
+    );
+
+    let asm32 = asm(source);
+    assert!(asm32.contains("fdivrp\n"));
+    assert!(asm32.contains("fsubrp\n"));
+    assert!(!asm32.contains("fxch"));
+
+    let mut options = Options::new();
+    options.target = Target::Elf64Linux;
+    let asm64 = asm_with(source, &options);
+    assert!(asm64.contains("movsd xmm1, [rsp]\nmovsd xmm0, [rsp+8]\nadd rsp, 8\ndivsd xmm0, xmm1\n"));
+}
@@ -0,0 +1,92 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use common::*;
+
+fn tree(source: &str) -> String {
+    parse(source).ok().unwrap().to_string()
+}
+
+fn main_of(body: &str) -> Vec<String> {
+    let source = "const N: integer = 2 + 3;\nvar i: integer;\nbegin\n".to_string() + body + "\nend.\n";
+    ir_function(&ir(&source), "main main")
+}
+
+#[test]
+fn constant_subexpression_becomes_literal() {
+    assert_eq!(main_of("i := (1 + 2) * 4 - 1;"), vec![".b0:", "push.i 11", "store.i @i"]);
+}
+
+#[test]
+fn constant_operand_of_runtime_expression_is_folded() {
+    assert_eq!(
+        main_of("i := 0;\ni := i + 2 * 3;")[3..].to_vec(),
+        vec!["load.i @i", "push.i 6", "op.i +", "store.i @i"]
+    );
+}
+
+#[test]
+fn const_declaration_is_propagated() {
+    assert_eq!(main_of("i := N * 4;"), vec![".b0:", "push.i 20", "store.i @i"]);
+    assert_eq!(
+        main_of("writeln(-N, succ(N));"),
+        vec![".b0:", "push.i 6", "push.i -5", "printf fmt_0 (i, i)"]
+    );
+}
+
+#[test]
+fn real_and_boolean_expressions_are_folded() {
+    assert_eq!(main_of("writeln(1.5 * 2);"), vec![".b0:", "push.d 3.0", "printf fmt_0 (d)"]);
+    assert_eq!(
+        main_of("writeln(not (N > 4));"),
+        vec![".b0:", "push.i 0", "bool.str bool_names", "printf fmt_0 (i)"]
+    );
+}
+
+#[test]
+fn known_condition_keeps_only_taken_branch() {
+    assert_eq!(
+        main_of("if N > 4 then begin writeln(1); end else begin writeln(2); end;"),
+        vec![".b0:", "push.i 1", "printf fmt_0 (i)"]
+    );
+    assert_eq!(
+        main_of("if N < 4 then begin writeln(1); end else begin writeln(2); end;"),
+        vec![".b0:", "push.i 2", "printf fmt_0 (i)"]
+    );
+}
+
+#[test]
+fn false_condition_without_else_leaves_nothing() {
+    assert_eq!(main_of("if false then begin writeln(3); end;\ni := 1;"), vec![".b0:", "push.i 1", "store.i @i"]);
+}
+
+#[test]
+fn unknown_condition_keeps_both_branches() {
+    let main = main_of("i := 1;\nif i > 4 then begin writeln(1); end else begin writeln(2); end;");
+    assert!(main.contains(&"cmp.i > else l_0".to_string()));
+    assert!(main.contains(&"printf fmt_0 (i)".to_string()));
+    assert!(main.contains(&"printf fmt_1 (i)".to_string()));
+}
+
+#[test]
+fn overflow_and_division_by_zero_are_left_to_runtime() {
+    assert_eq!(
+        main_of("i := 2147483647 + 1;"),
+        vec![".b0:", "push.i 2147483647", "push.i 1", "op.i +", "store.i @i"]
+    );
+    assert_eq!(
+        main_of("i := 1 div 0;"),
+        vec![".b0:", "push.i 1", "push.i 0", "op.i div", "store.i @i"]
+    );
+}
+
+#[test]
+fn folded_nodes_are_marked_in_tree() {
+    let tree = tree("const N: integer = 2 + 3;\nvar i: integer;\nbegin\n  i := N * 4 - 1;\n  i := 7;\nend.\n");
+    assert!(tree.contains("N(Integer) = 5"));
+    assert!(tree.contains("19: Integer (folded)"));
+    assert!(tree.contains("7: Integer\n"));
+    assert!(!tree.contains("7: Integer (folded)"));
+}
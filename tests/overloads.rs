@@ -0,0 +1,190 @@
+extern crate PasC;
+
+mod common;
+
+use common::*;
+
+const OVERLOADS: &str = "
+var i: integer;
+procedure h(x: integer);
+begin
+  writeln(x);
+end;
+procedure h(x: double);
+begin
+  writeln(x);
+end;
+begin
+  i := 1;
+  h(i);
+  h(2.5);
+end.
+";
+
+#[test]
+fn exact_match_wins_over_widening() {
+    let main = ir_function(&ir(OVERLOADS), "main main");
+    let calls: Vec<&String> = main.iter().filter(|line| line.starts_with("call")).collect();
+    assert_eq!(calls, vec!["call f_h_0", "call f_h_1"]);
+    assert!(!main.contains(&"i2d".to_string()));
+}
+
+#[test]
+fn integer_widens_to_double_parameter() {
+    let source = "
+procedure h(x: double);
+begin
+  writeln(x);
+end;
+begin
+  h(3);
+end.
+";
+    let main = ir_function(&ir(source), "main main");
+    assert_eq!(main[1..4].to_vec(), vec!["push.i 3", "i2d", "push.i 0"]);
+}
+
+#[test]
+fn subrange_prefers_base_type_over_double() {
+    let source = "
+type small = 1..10;
+var s: small;
+procedure h(x: double);
+begin
+  writeln(x);
+end;
+procedure h(x: integer);
+begin
+  writeln(x);
+end;
+begin
+  s := 3;
+  h(s);
+end.
+";
+    let main = ir_function(&ir(source), "main main");
+    let calls: Vec<&String> = main.iter().filter(|line| line.starts_with("call")).collect();
+    assert_eq!(calls, vec!["call f_h_1"]);
+    assert!(!main.contains(&"i2d".to_string()));
+}
+
+#[test]
+fn ambiguous_call_is_reported() {
+    let source = "
+procedure h(a: integer; b: double);
+begin
+end;
+procedure h(a: double; b: integer);
+begin
+end;
+begin
+  h(1, 1);
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0205"]);
+}
+
+#[test]
+fn unknown_overload_is_reported() {
+    let source = "
+procedure h(a: integer);
+begin
+end;
+procedure h(a: double);
+begin
+end;
+begin
+  h(1, 2);
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0204"]);
+}
+
+const VAR_PARAM: &str = "
+type point = record
+    x: integer;
+    y: integer;
+  end;
+var i: integer;
+    a: array[1..3] of integer;
+    p: point;
+procedure inc(var x: integer);
+begin
+  x := x + 1;
+end;
+";
+
+#[test]
+fn var_parameter_takes_addresses() {
+    let source = VAR_PARAM.to_string() + "
+begin
+  inc(i);
+  inc(a[2]);
+  inc(p.y);
+end.
+";
+    let main = ir_function(&ir(&source), "main main");
+    assert_eq!(main[1], "addr @i");
+    assert!(main.contains(&"addr @a".to_string()));
+    assert!(main.contains(&"add.i 4".to_string()));
+    assert_eq!(main.iter().filter(|line| *line == "call f_inc_0").count(), 3);
+}
+
+#[test]
+fn var_parameter_rejects_values() {
+    for arg in &["3", "i + 1", "-i"] {
+        let source = VAR_PARAM.to_string() + "begin\n  inc(" + arg + ");\nend.\n";
+        assert_eq!(parse_codes(&source), vec!["E0231"], "inc({})", arg);
+    }
+}
+
+#[test]
+fn var_parameter_rejects_constants() {
+    let source = "
+const k: integer = 4;
+procedure inc(var x: integer);
+begin
+  x := x + 1;
+end;
+begin
+  inc(k);
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0231"]);
+}
+
+#[test]
+fn value_overload_is_used_for_rvalue_argument() {
+    let source = "
+var i: integer;
+procedure h(var x: integer);
+begin
+  x := 0;
+end;
+procedure h(x: double);
+begin
+  writeln(x);
+end;
+begin
+  h(i);
+  h(3);
+end.
+";
+    let main = ir_function(&ir(source), "main main");
+    let calls: Vec<&String> = main.iter().filter(|line| line.starts_with("call")).collect();
+    assert_eq!(calls, vec!["call f_h_0", "call f_h_1"]);
+}
+
+#[test]
+fn call_result_is_not_assignable() {
+    let source = "
+function f(n: integer): integer;
+begin
+  f := n;
+end;
+begin
+  f(1) := 3;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0231"]);
+}
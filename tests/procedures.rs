@@ -0,0 +1,114 @@
+extern crate PasC;
+
+mod common;
+
+use common::*;
+
+fn calls(body: &[String]) -> Vec<String> {
+    body.iter().filter(|line| line.starts_with("call ")).cloned().collect()
+}
+
+#[test]
+fn nested_procedures_with_same_name_get_own_labels() {
+    let source = "
+procedure a;
+  procedure h;
+  begin
+    writeln(1);
+  end;
+begin
+  h;
+end;
+procedure b;
+  procedure h;
+  begin
+    writeln(2);
+  end;
+begin
+  h;
+end;
+begin
+  a;
+  b;
+end.
+";
+    let ir = ir(source);
+    assert_eq!(ir.matches("function f_h_1 ").count(), 1);
+    assert_eq!(ir.matches("function f_h_3 ").count(), 1);
+    assert_eq!(calls(&ir_function(&ir, "function f_a_0 (args 0, locals 0)")), vec!["call f_h_1"]);
+    assert_eq!(calls(&ir_function(&ir, "function f_b_2 (args 0, locals 0)")), vec!["call f_h_3"]);
+    assert_eq!(ir_function(&ir, "function f_h_3 (args 0, locals 0)")[1], "push.i 2");
+}
+
+#[test]
+fn inner_procedure_shadows_outer_one() {
+    let source = "
+procedure h;
+begin
+  writeln(1);
+end;
+procedure a;
+  procedure h;
+  begin
+    writeln(2);
+  end;
+begin
+  h;
+end;
+begin
+  h;
+  a;
+end.
+";
+    let ir = ir(source);
+    assert_eq!(calls(&ir_function(&ir, "main main")), vec!["call f_h_0", "call f_a_1"]);
+    assert_eq!(calls(&ir_function(&ir, "function f_a_1 (args 0, locals 0)")), vec!["call f_h_2"]);
+}
+
+#[test]
+fn recursive_call_uses_own_label() {
+    let source = "
+function f(n: integer): integer;
+begin
+  if n > 0 then begin f := f(n - 1); end else begin f := 0; end;
+end;
+begin
+  writeln(f(3));
+end.
+";
+    let ir = ir(source);
+    assert_eq!(calls(&ir_function(&ir, "function f_f_0 (args 4, locals 4)")), vec!["call f_f_0"]);
+}
+
+#[test]
+fn function_result_type_does_not_change_declared_type() {
+    let source = "
+type pt = record x: integer; d: double; end;
+     num = integer;
+var p: pt; i: num;
+function mk: pt;
+begin
+  mk.x := 1;
+end;
+function f: num;
+begin
+  f := 1;
+end;
+procedure setp(var q: pt);
+begin
+  q.x := 4;
+end;
+procedure inc(var x: num);
+begin
+  x := x + 1;
+end;
+begin
+  setp(p);
+  inc(i);
+end.
+";
+    assert_eq!(parse_codes(source), Vec::<String>::new());
+    let main = ir_function(&ir(source), "main main");
+    assert_eq!(main[1], "addr @p");
+    assert!(main.contains(&"addr @i".to_string()));
+}
@@ -0,0 +1,128 @@
+extern crate PasC;
+
+mod common;
+
+use PasC::*;
+use common::*;
+
+#[test]
+fn missing_semicolon_before_main_begin_keeps_body() {
+    let source = "
+var a: integer
+begin
+  a := 1;
+  a := zz;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0102", "E0202"]);
+}
+
+#[test]
+fn empty_assignment_keeps_next_statement() {
+    let source = "
+var a: integer;
+begin
+  a := ;
+  a := zz;
+  a := 2;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0101", "E0202"]);
+}
+
+#[test]
+fn errors_in_declarations_and_statements_are_all_reported() {
+    let source = "
+var a: integer;
+    b: foo;
+var c: integer
+begin
+  a := ;
+  a := zz;
+  while a do begin a := 1; end;
+  c := 1.5;
+  a := 1
+end.
+";
+    assert_eq!(
+        parse_codes(source),
+        vec!["E0202", "E0102", "E0101", "E0202", "E0220", "E0210", "E0102"]
+    );
+}
+
+#[test]
+fn bad_declaration_keeps_rest_of_section() {
+    let source = "
+var i, j: integer;
+    d: double;
+    k: integer;
+begin
+  d := 1.5;
+  k := 2;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0102"]);
+}
+
+#[test]
+fn bad_const_and_type_keep_rest_of_section() {
+    let source = "
+const a: integer = ;
+      b: integer = 2;
+type t = foo;
+     small = 1..10;
+var s: small;
+begin
+  s := b;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0101", "E0202"]);
+}
+
+#[test]
+fn bad_record_field_keeps_record() {
+    let source = "
+type pt = record
+    x: foo;
+    y: integer;
+  end;
+var p: pt;
+begin
+  p.y := 1;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0202"]);
+}
+
+#[test]
+fn missing_semicolon_between_statements() {
+    let source = "
+var a: integer;
+begin
+  a := 1
+  a := 2;
+  a := zz;
+end.
+";
+    assert_eq!(parse_codes(source), vec!["E0102", "E0202"]);
+}
+
+#[test]
+fn error_count_is_limited() {
+    let source = "
+var a: integer;
+begin
+  a := x1;
+  a := x2;
+  a := x3;
+  a := x4;
+end.
+";
+    let mut options = Options::new();
+    options.max_errors = 2;
+    let errors = match parse_with(source, &options) {
+        Ok(_) => panic!("ошибки не найдены"),
+        Err(errors) => codes(errors),
+    };
+    assert_eq!(errors, vec!["E0202", "E0202", "E0103"]);
+}